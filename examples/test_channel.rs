@@ -1,8 +1,15 @@
- use kick_api::KickApiClient;
+use kick_api::{KickApiClient, KickOAuth};
 
-  #[tokio::main]
-  async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let client = KickApiClient::new();
+/// Reads KICK_CLIENT_ID, KICK_CLIENT_SECRET and KICK_REDIRECT_URI from the
+/// environment or a `.env` file. Kick's API has no anonymous access, but an
+/// app access token is enough to read public channel info.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenvy::dotenv().ok();
+
+    let oauth = KickOAuth::from_env()?;
+    let token = oauth.get_app_access_token().await?;
+    let client = KickApiClient::with_token(token.access_token);
 
     println!("Fetching channel info for 'xqc'...");
 
@@ -18,5 +25,5 @@
         Err(e) => eprintln!("Error: {}", e),
     }
 
-      Ok(())
-  }
\ No newline at end of file
+    Ok(())
+}
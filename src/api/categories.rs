@@ -0,0 +1,144 @@
+use crate::error::{KickApiError, Result};
+use crate::http::HttpClient;
+use crate::models::Category;
+use crate::session::Session;
+
+/// Categories API - search and look up stream categories
+pub struct CategoriesApi<'a> {
+    client: &'a HttpClient,
+    session: &'a Session,
+    base_url: &'a str,
+    locale: Option<&'a str>,
+}
+
+impl<'a> CategoriesApi<'a> {
+    /// Create a new CategoriesApi instance
+    pub(crate) fn new(
+        client: &'a HttpClient,
+        session: &'a Session,
+        base_url: &'a str,
+        locale: Option<&'a str>,
+    ) -> Self {
+        Self {
+            client,
+            session,
+            base_url,
+            locale,
+        }
+    }
+
+    /// Search categories by name
+    ///
+    /// Returns the first page of results. Use
+    /// [`search_page`](Self::search_page) to fetch later pages.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let categories = client.categories().search("chatting").await?;
+    /// for category in categories {
+    ///     println!("{}: {}", category.id, category.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(&self, query: &str) -> Result<Vec<Category>> {
+        self.search_page(query, 1).await
+    }
+
+    /// Get one page of category search results
+    ///
+    /// Pages start at 1. An empty result means there are no more pages.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let mut page = 1;
+    /// loop {
+    ///     let categories = client.categories().search_page("fortnite", page).await?;
+    ///     if categories.is_empty() {
+    ///         break;
+    ///     }
+    ///     println!("Page {}: {} categories", page, categories.len());
+    ///     page += 1;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search_page(&self, query: &str, page: u32) -> Result<Vec<Category>> {
+        if page == 0 {
+            return Err(KickApiError::InvalidInput(
+                "Category search pages start at 1".to_string(),
+            ));
+        }
+        let token = self.session.bearer_token().await?;
+
+        let url = format!("{}/categories", self.base_url);
+        let request = self
+            .client
+            .get(&url)
+            .header("Accept", "*/*")
+            .query(&[("q", query), ("page", &page.to_string())])
+            .bearer_auth(&token);
+        let request = self.localized(request);
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_list(response, "Failed to search categories").await
+    }
+
+    /// Get a category by ID
+    ///
+    /// Returns [`KickApiError::NotFound`] if no category has this ID; see [`get_opt`](Self::get_opt).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let category = client.categories().get(15).await?;
+    /// println!("Category: {}", category.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, category_id: u32) -> Result<Category> {
+        let token = self.session.bearer_token().await?;
+
+        let url = format!("{}/categories/{}", self.base_url, category_id);
+        let request = self
+            .client
+            .get(&url)
+            .header("Accept", "*/*")
+            .bearer_auth(&token);
+        let request = self.localized(request);
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_single(response, "Failed to get category").await
+    }
+
+    /// Get a category by ID, or `None` if no category has this ID
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// if let Some(category) = client.categories().get_opt(15).await? {
+    ///     println!("Category: {}", category.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_opt(&self, category_id: u32) -> Result<Option<Category>> {
+        match self.get(category_id).await {
+            Ok(category) => Ok(Some(category)),
+            Err(KickApiError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    // Helper methods
+
+    /// Add the client's `Accept-Language` header, if a locale is set
+    fn localized(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.locale {
+            Some(locale) => request.header("Accept-Language", locale),
+            None => request,
+        }
+    }
+}
@@ -1,70 +1,120 @@
 use crate::error::{KickApiError, Result};
-use crate::models::Channel;
-use reqwest;
+use crate::http::HttpClient;
+use crate::models::{Channel, UpdateChannelRequest};
+use crate::scopes::Scope;
+use crate::session::Session;
+use futures_util::{StreamExt, TryStreamExt};
+
+/// Most slugs Kick accepts in a single `/channels` request
+const MAX_SLUGS_PER_REQUEST: usize = 50;
+
+/// How many chunked `/channels` requests run at the same time
+const MAX_CONCURRENT_REQUESTS: usize = 4;
 
 /// Channels API - handles all channel-related endpoints
 pub struct ChannelsApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
+    locale: Option<&'a str>,
 }
 
 impl<'a> ChannelsApi<'a> {
     /// Create a new ChannelsApi instance
     pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
+        client: &'a HttpClient,
+        session: &'a Session,
         base_url: &'a str,
+        locale: Option<&'a str>,
     ) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
+            locale,
         }
     }
 
     /// Get a channel by slug
     ///
+    /// Kick's API has no field selection parameter, so the full channel is
+    /// always returned. [`Channel`] tolerates missing fields, so it also
+    /// parses trimmed payloads (e.g. from a caching proxy).
+    ///
+    /// Kick's public API has no anonymous access, so this fails on a client
+    /// without a token. Public channel data doesn't need a user login
+    /// though: an app access token from
+    /// [`KickOAuth::get_app_access_token`](crate::KickOAuth::get_app_access_token)
+    /// is enough.
+    ///
     /// Requires OAuth token with `channel:read` scope
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let channel = client.channels().get("xqc").await?;
     /// println!("Channel: {}", channel.slug);
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn get(&self, channel_slug: &str) -> Result<Channel> {
-        super::require_token(self.token)?;
-
-        let url = format!("{}/channels", self.base_url);
-        let request = self
-            .client
-            .get(&url)
-            .header("Accept", "*/*")
-            .query(&[("slug", channel_slug)])
-            .bearer_auth(self.token.as_ref().unwrap());
+        self.get_opt(channel_slug)
+            .await?
+            .ok_or_else(|| KickApiError::NotFound(format!("channel '{}'", channel_slug)))
+    }
 
-        let response = crate::http::send_with_retry(self.client, request).await?;
-        if response.status().is_success() {
-            let body = response.text().await?;
+    /// Get a channel by slug, or `None` if no channel has this slug
+    ///
+    /// Like [`get`](Self::get), but a missing channel isn't an error.
+    ///
+    /// Requires OAuth token with `channel:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// match client.channels().get_opt("xqc").await? {
+    ///     Some(channel) => println!("{}", channel),
+    ///     None => println!("No such channel"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_opt(&self, channel_slug: &str) -> Result<Option<Channel>> {
+        Ok(self.get_chunk(&[channel_slug]).await?.into_iter().next())
+    }
 
-            #[derive(serde::Deserialize)]
-            struct ChannelsResponse {
-                data: Vec<Channel>,
-            }
+    /// Get several channels by slug
+    ///
+    /// Sends the slugs as repeated `slug` parameters, at most 50 per
+    /// request; longer lists are split and fetched a few requests at a time.
+    /// Slugs that match no channel are skipped, so the result can be shorter
+    /// than `slugs`. Channels come back in request chunk order.
+    ///
+    /// Requires OAuth token with `channel:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let channels = client.channels().get_many(&["xqc", "trainwreckstv"]).await?;
+    /// for channel in channels {
+    ///     println!("{}: {:?}", channel.slug, channel.stream_title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_many(&self, slugs: &[&str]) -> Result<Vec<Channel>> {
+        if slugs.is_empty() {
+            return Ok(Vec::new());
+        }
 
-            let resp: ChannelsResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+        let chunks: Vec<Vec<Channel>> =
+            futures_util::stream::iter(slugs.chunks(MAX_SLUGS_PER_REQUEST))
+                .map(|chunk| self.get_chunk(chunk))
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .try_collect()
+                .await?;
 
-            resp.data
-                .into_iter()
-                .next()
-                .ok_or_else(|| KickApiError::ApiError("Channel not found".to_string()))
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to get channel: {}",
-                response.status()
-            )))
-        }
+        Ok(chunks.into_iter().flatten().collect())
     }
 
     /// Get your own channels (the authenticated user's channels)
@@ -73,39 +123,102 @@ impl<'a> ChannelsApi<'a> {
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let my_channels = client.channels().get_mine().await?;
     /// for channel in my_channels {
     ///     println!("My channel: {}", channel.slug);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn get_mine(&self) -> Result<Vec<Channel>> {
-        super::require_token(self.token)?;
+        self.session.require_scope(Scope::ChannelRead)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels", self.base_url);
         let request = self
             .client
             .get(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
+            .bearer_auth(&token);
+        let request = self.localized(request);
 
-        let response = crate::http::send_with_retry(self.client, request).await?;
-        if response.status().is_success() {
-            let body = response.text().await?;
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_list(response, "Failed to get channels").await
+    }
 
-            #[derive(serde::Deserialize)]
-            struct ChannelsResponse {
-                data: Vec<Channel>,
-            }
+    /// Update the authenticated user's stream title and/or category
+    ///
+    /// Fails with `InvalidInput` before sending if neither field is set.
+    ///
+    /// Requires OAuth token with `channel:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::UpdateChannelRequest;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client
+    ///     .channels()
+    ///     .update(UpdateChannelRequest {
+    ///         stream_title: Some("Just chatting".to_string()),
+    ///         category_id: Some(15),
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(&self, request: UpdateChannelRequest) -> Result<()> {
+        if request.stream_title.is_none() && request.category_id.is_none() {
+            return Err(KickApiError::InvalidInput(
+                "Channel update must set a stream title or category".to_string(),
+            ));
+        }
+        self.session.require_scope(Scope::ChannelWrite)?;
+        let token = self.session.bearer_token().await?;
 
-            let resp: ChannelsResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+        let url = format!("{}/channels", self.base_url);
+        let request = self
+            .client
+            .patch(&url)
+            .header("Accept", "*/*")
+            .bearer_auth(&token)
+            .json(&request);
 
-            Ok(resp.data)
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        if response.status().is_success() {
+            Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to get channels: {}",
-                response.status()
-            )))
+            Err(crate::http::error_from_response(response, "Failed to update channel").await)
+        }
+    }
+
+    // Helper methods
+
+    /// Fetch the channels for one request's worth of slugs
+    async fn get_chunk(&self, slugs: &[&str]) -> Result<Vec<Channel>> {
+        self.session.require_scope(Scope::ChannelRead)?;
+        let token = self.session.bearer_token().await?;
+
+        let url = format!("{}/channels", self.base_url);
+        let slug_pairs: Vec<(&str, &str)> = slugs.iter().map(|slug| ("slug", *slug)).collect();
+        let request = self
+            .client
+            .get(&url)
+            .header("Accept", "*/*")
+            .query(&slug_pairs)
+            .bearer_auth(&token);
+        let request = self.localized(request);
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_list(response, "Failed to get channel").await
+    }
+
+    /// Add the client's `Accept-Language` header, if a locale is set
+    fn localized(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self.locale {
+            Some(locale) => request.header("Accept-Language", locale),
+            None => request,
         }
     }
 }
@@ -1,77 +1,376 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
 use crate::error::{KickApiError, Result};
-use crate::models::{SendMessageRequest, SendMessageResponse};
-use reqwest;
+use crate::http::HttpClient;
+use crate::live_chat::LiveChatClient;
+use crate::models::{
+    LiveChatMessage, MAX_CHAT_MESSAGE_LEN, MessageType, SendMessageRequest, SendMessageResponse,
+};
+use crate::scopes::Scope;
+use crate::session::Session;
 
 /// Chat API - handles chat message endpoints
 ///
 /// Scopes required: `chat:write`, `moderation:chat_message:manage`
+///
+/// Kick's public API has no endpoint for listing the viewers or chatters
+/// currently in a channel. Presence is only exposed through Pusher presence
+/// channels, which need an authenticated Kick web session, while
+/// [`LiveChatClient`] subscribes to the public chatroom channel and only sees
+/// people who send messages.
+///
+/// There is also no endpoint for reading or changing chat settings (slow
+/// mode, followers-only, etc.). [`LiveChatClient::chat_settings`] tracks them
+/// from the chatroom's live events instead.
 pub struct ChatApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
+    send_slots: &'a SendSlots,
+    cooldown: Option<Duration>,
+    idempotency_key: Option<String>,
 }
 
+/// Earliest time the next message may be sent, per broadcaster
+///
+/// `None` keys sends without an explicit `broadcaster_user_id`.
+pub(crate) type SendSlots = Arc<Mutex<HashMap<Option<u64>, Instant>>>;
+
 impl<'a> ChatApi<'a> {
     /// Create a new ChatApi instance
     pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
+        client: &'a HttpClient,
+        session: &'a Session,
         base_url: &'a str,
+        send_slots: &'a SendSlots,
     ) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
+            send_slots,
+            cooldown: None,
+            idempotency_key: None,
         }
     }
 
+    /// Enforce a minimum interval between sends to the same channel
+    ///
+    /// With a cooldown set, [`send_message`](Self::send_message) waits until
+    /// at least `interval` has passed since the previous send to the same
+    /// `broadcaster_user_id` before sending. Concurrent sends queue up one
+    /// `interval` apart. The send history is shared by all clones of the
+    /// [`KickApiClient`](crate::KickApiClient), so the cooldown holds across
+    /// tasks as long as every send uses it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let chat = client.chat().with_cooldown(Duration::from_millis(1500));
+    /// for line in ["one", "two", "three"] {
+    ///     chat.send_as_user(12345, line).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_cooldown(mut self, interval: Duration) -> Self {
+        self.cooldown = Some(interval);
+        self
+    }
+
+    /// Send `key` as the `Idempotency-Key` header on messages sent through
+    /// this instance
+    ///
+    /// Reuse the same key when resending a message after an error you can't
+    /// classify, so a server that deduplicates by key posts it only once.
+    /// With a key, timed out sends and server errors are also retried
+    /// following the client's [`RetryPolicy`](crate::RetryPolicy). Kick
+    /// doesn't document support for the header, so only set one if a
+    /// duplicate message is acceptable.
+    /// [`send_message_chunked`](Self::send_message_chunked) sends `{key}-1`,
+    /// `{key}-2`, ... for the messages it splits content into.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client
+    ///     .chat()
+    ///     .with_idempotency_key("announce-2024-01-01")
+    ///     .send_as_user(12345, "Stream starting!")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
     /// Send a chat message
     ///
+    /// [`MessageType::User`] messages must set `broadcaster_user_id`, and
+    /// content must be non-empty and at most [`MAX_CHAT_MESSAGE_LEN`]
+    /// characters; otherwise this fails with `InvalidInput` before sending.
+    /// Use [`send_message_chunked`](Self::send_message_chunked) for longer
+    /// content.
+    ///
+    /// Rate limited (429) sends are retried following the client's
+    /// [`RetryPolicy`](crate::RetryPolicy). Timeouts and server errors are
+    /// not, since the message may already have been posted, unless an
+    /// idempotency key is set with
+    /// [`with_idempotency_key`](Self::with_idempotency_key).
+    ///
     /// Requires OAuth token with `chat:write` scope
     ///
     /// # Example
     /// ```no_run
-    /// use kick_api::SendMessageRequest;
+    /// use kick_api::{MessageType, SendMessageRequest};
     ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let request = SendMessageRequest {
-    ///     r#type: "user".to_string(),
+    ///     r#type: MessageType::User,
     ///     content: "Hello chat!".to_string(),
     ///     broadcaster_user_id: Some(12345),
     ///     reply_to_message_id: None,
     /// };
     /// let response = client.chat().send_message(request).await?;
     /// println!("Message sent: {}", response.message_id);
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn send_message(&self, request: SendMessageRequest) -> Result<SendMessageResponse> {
-        super::require_token(self.token)?;
+        self.send_keyed(request, self.idempotency_key.as_deref())
+            .await
+    }
+
+    async fn send_keyed(
+        &self,
+        request: SendMessageRequest,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendMessageResponse> {
+        check_content(&request.content)?;
+        if request.r#type == MessageType::User && request.broadcaster_user_id.is_none() {
+            return Err(KickApiError::InvalidInput(
+                "User messages require a broadcaster_user_id".to_string(),
+            ));
+        }
+        self.session.require_scope(Scope::ChatWrite)?;
+        let token = self.session.bearer_token().await?;
+        self.wait_for_send_slot(request.broadcaster_user_id).await;
 
         let url = format!("{}/chat", self.base_url);
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, idempotency_key);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
-        if response.status().is_success() {
-            let body = response.text().await?;
+        crate::http::parse_data_single(response, "Failed to send message").await
+    }
 
-            #[derive(serde::Deserialize)]
-            struct DataResponse {
-                data: SendMessageResponse,
-            }
+    /// Send a message, split into several if it is too long
+    ///
+    /// Content that fits in [`MAX_CHAT_MESSAGE_LEN`] characters is sent
+    /// unchanged, as by [`send_message`](Self::send_message). Longer content
+    /// is split at whitespace into messages that fit, which are sent in
+    /// order. Each message keeps the original spacing; only the whitespace
+    /// at a split is dropped, and words longer than the limit are cut. Only
+    /// the first message keeps `reply_to_message_id`. Stops at the first
+    /// failed send; earlier messages stay sent.
+    ///
+    /// Requires OAuth token with `chat:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{MessageType, SendMessageRequest};
+    ///
+    /// # async fn example(client: kick_api::KickApiClient, output: String) -> kick_api::Result<()> {
+    /// let responses = client
+    ///     .chat()
+    ///     .send_message_chunked(SendMessageRequest {
+    ///         r#type: MessageType::User,
+    ///         content: output,
+    ///         broadcaster_user_id: Some(12345),
+    ///         reply_to_message_id: None,
+    ///     })
+    ///     .await?;
+    /// println!("Sent {} messages", responses.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_message_chunked(
+        &self,
+        request: SendMessageRequest,
+    ) -> Result<Vec<SendMessageResponse>> {
+        if request.content.chars().count() <= MAX_CHAT_MESSAGE_LEN {
+            return Ok(vec![self.send_message(request).await?]);
+        }
+        let chunks = split_message(&request.content, MAX_CHAT_MESSAGE_LEN);
+        if chunks.is_empty() {
+            return Ok(vec![self.send_message(request).await?]);
+        }
 
-            let resp: DataResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+        let mut responses = Vec::with_capacity(chunks.len());
+        let mut reply_to_message_id = request.reply_to_message_id;
+        for (i, content) in chunks.into_iter().enumerate() {
+            let chunk = SendMessageRequest {
+                r#type: request.r#type,
+                content,
+                broadcaster_user_id: request.broadcaster_user_id,
+                reply_to_message_id: reply_to_message_id.take(),
+            };
+            let key = self
+                .idempotency_key
+                .as_ref()
+                .map(|key| format!("{}-{}", key, i + 1));
+            responses.push(self.send_keyed(chunk, key.as_deref()).await?);
+        }
+        Ok(responses)
+    }
 
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to send message: {}",
-                response.status()
+    /// Send a message as the authenticated user to a broadcaster's chat
+    ///
+    /// Requires OAuth token with `chat:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client.chat().send_as_user(12345, "Hello chat!").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_as_user(
+        &self,
+        broadcaster_user_id: u64,
+        content: &str,
+    ) -> Result<SendMessageResponse> {
+        self.send_message(SendMessageRequest {
+            r#type: MessageType::User,
+            content: content.to_string(),
+            broadcaster_user_id: Some(broadcaster_user_id),
+            reply_to_message_id: None,
+        })
+        .await
+    }
+
+    /// Reply to a message in a broadcaster's chat
+    ///
+    /// See also [`LiveChatMessage::reply`] for replying to a message read
+    /// from live chat.
+    ///
+    /// Requires OAuth token with `chat:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client.chat().reply(12345, "message_id_here", "Thanks!").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reply(
+        &self,
+        broadcaster_user_id: u64,
+        reply_to_message_id: &str,
+        content: &str,
+    ) -> Result<SendMessageResponse> {
+        self.send_message(SendMessageRequest {
+            r#type: MessageType::User,
+            content: content.to_string(),
+            broadcaster_user_id: Some(broadcaster_user_id),
+            reply_to_message_id: Some(reply_to_message_id.to_string()),
+        })
+        .await
+    }
+
+    /// Send a message as a bot to the token owner's chat
+    ///
+    /// Requires OAuth token with `chat:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client.chat().send_as_bot("Stream starting soon!").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_as_bot(&self, content: &str) -> Result<SendMessageResponse> {
+        self.send_message(SendMessageRequest {
+            r#type: MessageType::Bot,
+            content: content.to_string(),
+            broadcaster_user_id: None,
+            reply_to_message_id: None,
+        })
+        .await
+    }
+
+    /// Send a chat message and wait for it to appear in live chat
+    ///
+    /// Sends the message over REST, then reads `live_chat` until a message
+    /// with the returned message ID arrives. `live_chat` must already be
+    /// connected to the chatroom the message is sent to. Other messages read
+    /// while waiting are discarded.
+    ///
+    /// Returns an error if the message doesn't show up within `timeout` or
+    /// the live chat connection closes first.
+    ///
+    /// Requires OAuth token with `chat:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use kick_api::{LiveChatClient, MessageType, SendMessageRequest};
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let mut live = LiveChatClient::connect(27670567).await?;
+    /// let request = SendMessageRequest {
+    ///     r#type: MessageType::User,
+    ///     content: "ping".to_string(),
+    ///     broadcaster_user_id: Some(12345),
+    ///     reply_to_message_id: None,
+    /// };
+    /// let echoed = client
+    ///     .chat()
+    ///     .send_and_confirm(&mut live, request, Duration::from_secs(10))
+    ///     .await?;
+    /// println!("Seen in chat at {:?}", echoed.created_at);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_and_confirm(
+        &self,
+        live_chat: &mut LiveChatClient,
+        request: SendMessageRequest,
+        timeout: Duration,
+    ) -> Result<LiveChatMessage> {
+        let response = self.send_message(request).await?;
+        let message_id = response.message_id;
+
+        let wait = async {
+            while let Some(msg) = live_chat.next_message().await? {
+                if msg.id == message_id {
+                    return Ok(msg);
+                }
+            }
+            Err(KickApiError::UnexpectedError(format!(
+                "Live chat closed before message '{message_id}' appeared"
             )))
-        }
+        };
+
+        tokio::time::timeout(timeout, wait).await.map_err(|_| {
+            KickApiError::UnexpectedError(format!(
+                "Message '{}' did not appear in live chat within {:?}",
+                message_id, timeout
+            ))
+        })?
     }
 
     /// Delete a chat message
@@ -80,27 +379,158 @@ impl<'a> ChatApi<'a> {
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// client.chat().delete_message("message_id_here").await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn delete_message(&self, message_id: &str) -> Result<()> {
-        super::require_token(self.token)?;
+        self.session
+            .require_scope(Scope::ModerationChatMessageManage)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/chat/{}", self.base_url, message_id);
         let request = self
             .client
             .delete(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
-        let response = crate::http::send_with_retry(self.client, request).await?;
+            .bearer_auth(&token);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to delete message: {}",
-                response.status()
-            )))
+            Err(crate::http::error_from_response(response, "Failed to delete message").await)
         }
     }
 
+    // Helper methods
+
+    /// Reserve the next send slot for `broadcaster_user_id` and wait for it
+    async fn wait_for_send_slot(&self, broadcaster_user_id: Option<u64>) {
+        let Some(interval) = self.cooldown else {
+            return;
+        };
+
+        let slot = {
+            let mut slots = self
+                .send_slots
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            let now = Instant::now();
+            let slot = slots
+                .get(&broadcaster_user_id)
+                .map_or(now, |&next| next.max(now));
+            slots.insert(broadcaster_user_id, slot + interval);
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Reject empty or over-long message content
+fn check_content(content: &str) -> Result<()> {
+    if content.trim().is_empty() {
+        return Err(KickApiError::InvalidInput(
+            "Chat message content is empty".to_string(),
+        ));
+    }
+    let len = content.chars().count();
+    if len > MAX_CHAT_MESSAGE_LEN {
+        return Err(KickApiError::InvalidInput(format!(
+            "Chat message is {} characters, the limit is {}",
+            len, MAX_CHAT_MESSAGE_LEN
+        )));
+    }
+    Ok(())
+}
+
+/// Split `content` into pieces of at most `max_len` characters
+///
+/// Breaks at the last whitespace that keeps a piece within `max_len`,
+/// dropping the whitespace around the break and keeping everything else as
+/// written. Words longer than `max_len` are cut into `max_len`-character
+/// pieces.
+fn split_message(content: &str, max_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut rest = content;
+
+    while !rest.trim().is_empty() {
+        if rest.chars().count() <= max_len {
+            chunks.push(rest.to_string());
+            break;
+        }
+
+        // A break at char `max_len` still leaves `max_len` chars before it
+        let mut seen_text = false;
+        let mut last_break = None;
+        let mut hard_cut = rest.len();
+        for (n, (i, c)) in rest.char_indices().take(max_len + 1).enumerate() {
+            if n == max_len {
+                hard_cut = i;
+            }
+            if c.is_whitespace() {
+                if seen_text {
+                    last_break = Some(i);
+                }
+            } else {
+                seen_text = true;
+            }
+        }
+
+        let cut = last_break.unwrap_or(hard_cut);
+        let chunk = rest[..cut].trim_end();
+        if !chunk.is_empty() {
+            chunks.push(chunk.to_string());
+        }
+        rest = rest[cut..].trim_start();
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_content() {
+        assert!(check_content("hi").is_ok());
+        assert!(check_content(" \n").is_err());
+        assert!(check_content(&"a".repeat(MAX_CHAT_MESSAGE_LEN)).is_ok());
+        assert!(check_content(&"a".repeat(MAX_CHAT_MESSAGE_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_split_message() {
+        assert!(split_message("  ", 10).is_empty());
+        assert_eq!(split_message("hello world", 11), ["hello world"]);
+        assert_eq!(
+            split_message("hello big\n wide world", 10),
+            ["hello big", "wide world"]
+        );
+        assert_eq!(
+            split_message("ab abcdefghij", 4),
+            ["ab", "abcd", "efgh", "ij"]
+        );
+
+        let long = "wörd ".repeat(300);
+        let chunks = split_message(&long, MAX_CHAT_MESSAGE_LEN);
+        assert!(
+            chunks
+                .iter()
+                .all(|c| c.chars().count() <= MAX_CHAT_MESSAGE_LEN)
+        );
+        assert_eq!(chunks.join(" "), long);
+    }
+
+    #[test]
+    fn test_split_message_keeps_spacing() {
+        assert_eq!(split_message("a  b\n\nc d", 6), ["a  b", "c d"]);
+        assert_eq!(
+            split_message("  indented  line\ttabbed", 16),
+            ["  indented  line", "tabbed"]
+        );
+        assert_eq!(split_message(&format!("{}ab", " ".repeat(5)), 4), ["ab"]);
+    }
 }
@@ -1,133 +1,197 @@
-use crate::error::{KickApiError, Result};
-use crate::models::{EventSubscription, SubscribeRequest, SubscribeResult};
-use reqwest;
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::models::{
+    EventSubscription, KickEvent, SubscribeEvent, SubscribePartialError, SubscribeRequest,
+    SubscribeResult,
+};
+use crate::scopes::Scope;
+use crate::session::Session;
 
 /// Events API - handles webhook/event subscription endpoints
 ///
 /// Scopes required: `events:subscribe`
 pub struct EventsApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
 }
 
 impl<'a> EventsApi<'a> {
     /// Create a new EventsApi instance
-    pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
-        base_url: &'a str,
-    ) -> Self {
+    pub(crate) fn new(client: &'a HttpClient, session: &'a Session, base_url: &'a str) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
         }
     }
 
     /// List active event subscriptions
     ///
-    /// Optionally filter by broadcaster user ID.
+    /// Optionally filter by broadcaster user ID. Kick returns every
+    /// subscription in one unpaginated response.
     ///
     /// Requires OAuth token with `events:subscribe` scope
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// // List all subscriptions
     /// let subs = client.events().list(None).await?;
     ///
     /// // List subscriptions for a specific broadcaster
     /// let subs = client.events().list(Some(12345)).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn list(
-        &self,
-        broadcaster_user_id: Option<u64>,
-    ) -> Result<Vec<EventSubscription>> {
-        super::require_token(self.token)?;
+    pub async fn list(&self, broadcaster_user_id: Option<u64>) -> Result<Vec<EventSubscription>> {
+        self.session.require_scope(Scope::EventsSubscribe)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/events/subscriptions", self.base_url);
         let mut request = self
             .client
             .get(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
+            .bearer_auth(&token);
 
         if let Some(id) = broadcaster_user_id {
             request = request.query(&[("broadcaster_user_id", id)]);
         }
 
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
-        if response.status().is_success() {
-            let body = response.text().await?;
-
-            #[derive(serde::Deserialize)]
-            struct DataResponse {
-                data: Vec<EventSubscription>,
-            }
-
-            let resp: DataResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
-
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to list event subscriptions: {}",
-                response.status()
-            )))
-        }
+        crate::http::parse_data_list(response, "Failed to list event subscriptions").await
     }
 
     /// Subscribe to events
     ///
+    /// The request is validated first (see [`SubscribeRequest::validate`]),
+    /// so an empty event name or a version of 0 fails locally with
+    /// [`KickApiError::InvalidInput`](crate::KickApiError::InvalidInput)
+    /// naming the offending event. When the client knows the token's granted
+    /// scopes (see
+    /// [`KickApiClient::with_granted_scopes`](crate::KickApiClient::with_granted_scopes)),
+    /// an event whose [`KickEvent::required_scope`] is missing fails locally
+    /// with [`KickApiError::InsufficientScope`](crate::KickApiError::InsufficientScope).
+    ///
     /// Requires OAuth token with `events:subscribe` scope
     ///
     /// # Example
     /// ```no_run
-    /// use kick_api::{SubscribeRequest, SubscribeEvent};
+    /// use kick_api::{KickEvent, SubscribeEvent, SubscribeRequest};
     ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let request = SubscribeRequest {
     ///     broadcaster_user_id: Some(12345),
     ///     method: "webhook".to_string(),
-    ///     events: vec![
-    ///         SubscribeEvent { name: "chat.message.created".to_string(), version: 1 },
-    ///     ],
+    ///     events: vec![SubscribeEvent::from_event(KickEvent::ChatMessageSent)],
     /// };
     /// let results = client.events().subscribe(request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn subscribe(
-        &self,
-        request: SubscribeRequest,
-    ) -> Result<Vec<SubscribeResult>> {
-        super::require_token(self.token)?;
+    pub async fn subscribe(&self, request: SubscribeRequest) -> Result<Vec<SubscribeResult>> {
+        self.session.require_scope(Scope::EventsSubscribe)?;
+        let token = self.session.bearer_token().await?;
+        request.validate()?;
+        for event in request
+            .events
+            .iter()
+            .filter_map(|e| KickEvent::from_name(&e.name))
+        {
+            self.session.require_scope(event.required_scope())?;
+        }
 
         let url = format!("{}/events/subscriptions", self.base_url);
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
-        if response.status().is_success() {
-            let body = response.text().await?;
+        crate::http::parse_data_list(response, "Failed to subscribe to events").await
+    }
 
-            #[derive(serde::Deserialize)]
-            struct DataResponse {
-                data: Vec<SubscribeResult>,
-            }
+    /// Subscribe to events, failing unless every event subscribed
+    ///
+    /// Returns the new subscription IDs when all events succeeded.
+    /// Otherwise fails with
+    /// [`KickApiError::SubscribePartial`](crate::KickApiError::SubscribePartial),
+    /// listing the failed events alongside the IDs of the subscriptions that
+    /// were created (those stay active). Use [`subscribe`](Self::subscribe) for
+    /// the raw per-event results.
+    ///
+    /// Requires OAuth token with `events:subscribe` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickApiError;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient, request: kick_api::SubscribeRequest) -> kick_api::Result<()> {
+    /// match client.events().subscribe_checked(request).await {
+    ///     Ok(ids) => println!("Subscribed: {:?}", ids),
+    ///     Err(KickApiError::SubscribePartial(partial)) => {
+    ///         for failed in &partial.failed {
+    ///             println!("{} failed: {:?}", failed.name, failed.error);
+    ///         }
+    ///     }
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subscribe_checked(&self, request: SubscribeRequest) -> Result<Vec<String>> {
+        let results = self.subscribe(request).await?;
+        SubscribePartialError::check(results)
+    }
 
-            let resp: DataResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+    /// Retry only the events that failed in a previous `subscribe` call
+    ///
+    /// Rebuilds a [`SubscribeRequest`] from the results that carry an
+    /// `error` and subscribes to just those. If nothing failed, returns an
+    /// empty list without making a request.
+    ///
+    /// Requires OAuth token with `events:subscribe` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, request: kick_api::SubscribeRequest) -> kick_api::Result<()> {
+    /// let results = client.events().subscribe(request).await?;
+    /// let retried = client
+    ///     .events()
+    ///     .retry_failed(&results, Some(12345), "webhook")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn retry_failed(
+        &self,
+        results: &[SubscribeResult],
+        broadcaster_user_id: Option<u64>,
+        method: &str,
+    ) -> Result<Vec<SubscribeResult>> {
+        let events: Vec<SubscribeEvent> = results
+            .iter()
+            .filter(|r| r.error.is_some())
+            .map(|r| SubscribeEvent {
+                name: r.name.clone(),
+                version: r.version,
+            })
+            .collect();
 
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to subscribe to events: {}",
-                response.status()
-            )))
+        if events.is_empty() {
+            return Ok(Vec::new());
         }
+
+        self.subscribe(SubscribeRequest {
+            broadcaster_user_id,
+            method: method.to_string(),
+            events,
+        })
+        .await
     }
 
     /// Unsubscribe from events by subscription IDs
@@ -136,10 +200,14 @@ impl<'a> EventsApi<'a> {
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// client.events().unsubscribe(vec!["sub_id_1".to_string(), "sub_id_2".to_string()]).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn unsubscribe(&self, ids: Vec<String>) -> Result<()> {
-        super::require_token(self.token)?;
+        self.session.require_scope(Scope::EventsSubscribe)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/events/subscriptions", self.base_url);
         let id_pairs: Vec<(&str, &str)> = ids.iter().map(|id| ("id", id.as_str())).collect();
@@ -148,18 +216,106 @@ impl<'a> EventsApi<'a> {
             .client
             .delete(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .query(&id_pairs);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to unsubscribe from events: {}",
-                response.status()
-            )))
+            Err(
+                crate::http::error_from_response(response, "Failed to unsubscribe from events")
+                    .await,
+            )
+        }
+    }
+
+    /// Unsubscribe from every event subscription
+    ///
+    /// Lists the subscriptions (only those for `broadcaster_user_id`, if
+    /// given) and deletes them in one request. Does nothing if there are
+    /// none.
+    ///
+    /// Requires OAuth token with `events:subscribe` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// // Clean up on shutdown
+    /// client.events().unsubscribe_all(Some(12345)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unsubscribe_all(&self, broadcaster_user_id: Option<u64>) -> Result<()> {
+        let ids: Vec<String> = self
+            .list(broadcaster_user_id)
+            .await?
+            .into_iter()
+            .map(|subscription| subscription.id)
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(());
         }
+        self.unsubscribe(ids).await
     }
 
+    /// Get Kick's public key for verifying webhook signatures
+    ///
+    /// Returns the PEM encoded key to pass to
+    /// [`verify_signature`](crate::verify_signature). No token is needed.
+    /// Kick rarely rotates the key, so fetch it once at startup and refetch
+    /// only if valid-looking deliveries start failing verification.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let public_key = client.events().public_key().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn public_key(&self) -> Result<String> {
+        let url = format!("{}/public-key", self.base_url);
+        let request = self.client.get(&url).header("Accept", "*/*");
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        #[derive(serde::Deserialize)]
+        struct PublicKeyData {
+            public_key: String,
+        }
+
+        let data: PublicKeyData =
+            crate::http::parse_data_single(response, "Failed to get public key").await?;
+        Ok(data.public_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::KickApiError;
+
+    #[tokio::test]
+    async fn test_subscribe_checks_event_scopes() {
+        let client = HttpClient::default();
+        let session = Session::new(Some("test_token".to_string()));
+        session.set_granted_scopes(Some(["events:subscribe".to_string()].into()));
+        // Nothing listens here; the scope check fails before sending
+        let events = EventsApi::new(&client, &session, "http://127.0.0.1:9");
+
+        let request = SubscribeRequest {
+            broadcaster_user_id: Some(1),
+            method: "webhook".to_string(),
+            events: vec![
+                SubscribeEvent::new("chat.message.sent", 1).unwrap(),
+                SubscribeEvent::new("channel.reward.redemption.updated", 1).unwrap(),
+            ],
+        };
+        match events.subscribe(request).await {
+            Err(KickApiError::InsufficientScope { required, .. }) => {
+                assert_eq!(required, Scope::ChannelRewardsRead);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
 }
@@ -0,0 +1,123 @@
+use crate::error::{KickApiError, Result};
+use crate::http::HttpClient;
+use crate::models::{Livestream, LivestreamFilter};
+use crate::session::Session;
+
+/// Livestreams API - browse channels that are currently live
+pub struct LivestreamsApi<'a> {
+    client: &'a HttpClient,
+    session: &'a Session,
+    base_url: &'a str,
+}
+
+impl<'a> LivestreamsApi<'a> {
+    /// Create a new LivestreamsApi instance
+    pub(crate) fn new(client: &'a HttpClient, session: &'a Session, base_url: &'a str) -> Self {
+        Self {
+            client,
+            session,
+            base_url,
+        }
+    }
+
+    /// List live streams matching a filter
+    ///
+    /// Filtering and sorting happen server-side. Works with an app access
+    /// token; no scope is required.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{LivestreamFilter, LivestreamSort};
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let streams = client
+    ///     .livestreams()
+    ///     .list(LivestreamFilter {
+    ///         language: Some("en".to_string()),
+    ///         sort: Some(LivestreamSort::ViewerCount),
+    ///         limit: Some(25),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// for stream in streams {
+    ///     println!("{}: {} viewers", stream.slug, stream.viewer_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self, filter: LivestreamFilter) -> Result<Vec<Livestream>> {
+        let query = filter_query(&filter)?;
+        let token = self.session.bearer_token().await?;
+
+        let url = format!("{}/livestreams", self.base_url);
+        let request = self
+            .client
+            .get(&url)
+            .header("Accept", "*/*")
+            .query(&query)
+            .bearer_auth(&token);
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_list(response, "Failed to list livestreams").await
+    }
+}
+
+/// Build the query parameters for a filter
+fn filter_query(filter: &LivestreamFilter) -> Result<Vec<(&'static str, String)>> {
+    let mut query = Vec::new();
+    if let Some(category_id) = filter.category_id {
+        query.push(("category_id", category_id.to_string()));
+    }
+    if let Some(language) = &filter.language {
+        query.push(("language", language.clone()));
+    }
+    if let Some(sort) = filter.sort {
+        query.push(("sort", sort.as_str().to_string()));
+    }
+    if let Some(limit) = filter.limit {
+        if !(1..=100).contains(&limit) {
+            return Err(KickApiError::InvalidInput(format!(
+                "Livestream limit must be between 1 and 100, got {}",
+                limit
+            )));
+        }
+        query.push(("limit", limit.to_string()));
+    }
+    Ok(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LivestreamSort;
+
+    #[test]
+    fn test_filter_query() {
+        assert!(
+            filter_query(&LivestreamFilter::default())
+                .unwrap()
+                .is_empty()
+        );
+
+        let filter = LivestreamFilter {
+            category_id: Some(15),
+            sort: Some(LivestreamSort::StartedAt),
+            limit: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter_query(&filter).unwrap(),
+            vec![
+                ("category_id", "15".to_string()),
+                ("sort", "started_at".to_string()),
+                ("limit", "5".to_string()),
+            ]
+        );
+
+        let too_many = LivestreamFilter {
+            limit: Some(500),
+            ..Default::default()
+        };
+        assert!(filter_query(&too_many).is_err());
+    }
+}
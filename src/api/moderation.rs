@@ -1,34 +1,65 @@
-use crate::error::{KickApiError, Result};
-use crate::models::{BanRequest, UnbanRequest};
-use reqwest;
+use std::time::Duration;
+
+use futures_util::StreamExt;
+
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::models::{BanRequest, BulkModerationResult, FailedModeration, UnbanRequest};
+use crate::scopes::Scope;
+use crate::session::Session;
+
+/// How many requests `ban_many` and `unban_many` run at the same time
+const MAX_CONCURRENT_REQUESTS: usize = 4;
 
 /// Moderation API - handles ban/unban endpoints
 ///
 /// Scopes required: `moderation:ban`
+///
+/// Kick's public API has no endpoint listing a channel's active bans or
+/// timeouts; bans can only be issued and lifted. To keep a ban list, record
+/// them as they happen: the `moderation.banned` webhook
+/// ([`ModerationBanned`](crate::ModerationBanned)) carries the banned user,
+/// moderator, reason and expiry, and live chat emits
+/// [`UserBanned`](crate::UserBanned) for bans in a connected chatroom. Bans
+/// issued before you started recording can't be recovered.
 pub struct ModerationApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
+    idempotency_key: Option<String>,
 }
 
 impl<'a> ModerationApi<'a> {
     /// Create a new ModerationApi instance
-    pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
-        base_url: &'a str,
-    ) -> Self {
+    pub(crate) fn new(client: &'a HttpClient, session: &'a Session, base_url: &'a str) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
+            idempotency_key: None,
         }
     }
 
+    /// Send `key` as the `Idempotency-Key` header on bans and unbans sent
+    /// through this instance
+    ///
+    /// Reuse the same key when resending after an error you can't classify.
+    /// With a key, timed out bans and server errors are also retried
+    /// following the client's [`RetryPolicy`](crate::RetryPolicy). Kick
+    /// doesn't document support for the header. [`ban_many`](Self::ban_many) and
+    /// [`unban_many`](Self::unban_many) send `{key}-{user_id}` for each user.
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
     /// Ban or timeout a user in a channel
     ///
     /// If `duration` is provided in the request, this is a timeout (temporary ban).
-    /// If `duration` is `None`, this is a permanent ban.
+    /// If `duration` is `None`, this is a permanent ban. Prefer building the
+    /// request with [`BanRequest::permanent`] or [`BanRequest::timeout`] to
+    /// make the intent explicit. Out-of-range timeout durations are rejected
+    /// locally with `KickApiError::InvalidInput`.
     ///
     /// Requires OAuth token with `moderation:ban` scope
     ///
@@ -36,6 +67,7 @@ impl<'a> ModerationApi<'a> {
     /// ```no_run
     /// use kick_api::BanRequest;
     ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// // Permanent ban
     /// let request = BanRequest {
     ///     broadcaster_user_id: 12345,
@@ -44,29 +76,71 @@ impl<'a> ModerationApi<'a> {
     ///     duration: None,
     /// };
     /// client.moderation().ban(request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn ban(&self, request: BanRequest) -> Result<()> {
-        super::require_token(self.token)?;
+        self.ban_keyed(request, self.idempotency_key.as_deref())
+            .await
+    }
+
+    async fn ban_keyed(&self, request: BanRequest, idempotency_key: Option<&str>) -> Result<()> {
+        self.session.require_scope(Scope::ModerationBan)?;
+        let token = self.session.bearer_token().await?;
+        request.validate()?;
 
         let url = format!("{}/moderation/bans", self.base_url);
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, idempotency_key);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to ban user: {}",
-                response.status()
-            )))
+            Err(crate::http::error_from_response(response, "Failed to ban user").await)
         }
     }
 
+    /// Time out a user in a channel for `duration`
+    ///
+    /// `duration` is rounded down to whole seconds and must be between 1
+    /// second and [`MAX_TIMEOUT_SECS`](crate::MAX_TIMEOUT_SECS); otherwise
+    /// this fails with `KickApiError::InvalidInput` before sending. Use
+    /// [`ban`](Self::ban) with [`BanRequest::permanent`] for permanent bans.
+    ///
+    /// Requires OAuth token with `moderation:ban` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client
+    ///     .moderation()
+    ///     .timeout(12345, 67890, Duration::from_secs(10 * 60), Some("Cool off"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn timeout(
+        &self,
+        broadcaster_user_id: u64,
+        user_id: u64,
+        duration: Duration,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        // Anything past u32::MAX is over the limit anyway
+        let seconds = u32::try_from(duration.as_secs()).unwrap_or(u32::MAX);
+        let mut request = BanRequest::timeout(broadcaster_user_id, user_id, seconds)?;
+        request.reason = reason.map(str::to_string);
+        self.ban(request).await
+    }
+
     /// Unban a user in a channel
     ///
     /// Requires OAuth token with `moderation:ban` scope
@@ -75,32 +149,166 @@ impl<'a> ModerationApi<'a> {
     /// ```no_run
     /// use kick_api::UnbanRequest;
     ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let request = UnbanRequest {
     ///     broadcaster_user_id: 12345,
     ///     user_id: 67890,
     /// };
     /// client.moderation().unban(request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn unban(&self, request: UnbanRequest) -> Result<()> {
-        super::require_token(self.token)?;
+        self.unban_keyed(request, self.idempotency_key.as_deref())
+            .await
+    }
+
+    async fn unban_keyed(
+        &self,
+        request: UnbanRequest,
+        idempotency_key: Option<&str>,
+    ) -> Result<()> {
+        self.session.require_scope(Scope::ModerationBan)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/moderation/bans", self.base_url);
         let request = self
             .client
             .delete(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, idempotency_key);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to unban user: {}",
-                response.status()
-            )))
+            Err(crate::http::error_from_response(response, "Failed to unban user").await)
         }
     }
 
+    /// Ban or time out several users in one channel
+    ///
+    /// Each request's `broadcaster_user_id` is set to `broadcaster_user_id`.
+    /// Requests run a few at a time, and a failure doesn't stop the others;
+    /// check [`BulkModerationResult::failed`] for the users that weren't
+    /// banned.
+    ///
+    /// Requires OAuth token with `moderation:ban` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::BanRequest;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient, raiders: Vec<u64>) -> kick_api::Result<()> {
+    /// let requests = raiders
+    ///     .into_iter()
+    ///     .map(|user_id| BanRequest::permanent(12345, user_id).reason("Hate raid"))
+    ///     .collect();
+    /// let result = client.moderation().ban_many(12345, requests).await;
+    /// for failed in &result.failed {
+    ///     println!("Could not ban {}: {}", failed.user_id, failed.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ban_many(
+        &self,
+        broadcaster_user_id: u64,
+        requests: Vec<BanRequest>,
+    ) -> BulkModerationResult {
+        let outcomes = futures_util::stream::iter(requests)
+            .map(|mut request| async move {
+                request.broadcaster_user_id = broadcaster_user_id;
+                let user_id = request.user_id;
+                let key = self.user_key(user_id);
+                (user_id, self.ban_keyed(request, key.as_deref()).await)
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+        collect_outcomes(outcomes)
+    }
+
+    /// Unban several users in one channel
+    ///
+    /// Works like [`ban_many`](Self::ban_many).
+    ///
+    /// Requires OAuth token with `moderation:ban` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let result = client.moderation().unban_many(12345, vec![67890, 13579]).await;
+    /// println!("Unbanned {} users", result.succeeded.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unban_many(
+        &self,
+        broadcaster_user_id: u64,
+        user_ids: Vec<u64>,
+    ) -> BulkModerationResult {
+        let outcomes = futures_util::stream::iter(user_ids)
+            .map(|user_id| async move {
+                let request = UnbanRequest {
+                    broadcaster_user_id,
+                    user_id,
+                };
+                let key = self.user_key(user_id);
+                (user_id, self.unban_keyed(request, key.as_deref()).await)
+            })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect::<Vec<_>>()
+            .await;
+        collect_outcomes(outcomes)
+    }
+
+    /// The idempotency key for one user's request in a bulk call
+    fn user_key(&self, user_id: u64) -> Option<String> {
+        self.idempotency_key
+            .as_ref()
+            .map(|key| format!("{}-{}", key, user_id))
+    }
+}
+
+/// Sort per-user outcomes into successes and failures
+fn collect_outcomes(outcomes: Vec<(u64, Result<()>)>) -> BulkModerationResult {
+    let mut result = BulkModerationResult::default();
+    for (user_id, outcome) in outcomes {
+        match outcome {
+            Ok(()) => result.succeeded.push(user_id),
+            Err(error) => result.failed.push(FailedModeration { user_id, error }),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BanRequest, KickApiClient};
+    use warp::Filter;
+
+    #[tokio::test]
+    async fn test_ban_many_reports_failures() {
+        let route = warp::path!("public" / "v1" / "moderation" / "bans")
+            .and(warp::body::json())
+            .map(|body: serde_json::Value| {
+                let status = if body["user_id"] == 2 {
+                    warp::http::StatusCode::BAD_REQUEST
+                } else {
+                    warp::http::StatusCode::OK
+                };
+                warp::reply::with_status(r#"{"data":{}}"#, status)
+            });
+        let client = KickApiClient::mock_client(route);
+
+        let requests = (1..=3).map(|id| BanRequest::permanent(0, id)).collect();
+        let result = client.moderation().ban_many(12345, requests).await;
+        assert_eq!(result.succeeded, [1, 3]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].user_id, 2);
+        assert!(!result.is_complete());
+    }
 }
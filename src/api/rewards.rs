@@ -1,97 +1,206 @@
+use std::collections::HashSet;
+
 use crate::error::{KickApiError, Result};
+use crate::http::HttpClient;
 use crate::models::{
     ChannelReward, ChannelRewardRedemption, CreateRewardRequest, ManageRedemptionsRequest,
-    ManageRedemptionsResponse, RedemptionStatus, UpdateRewardRequest,
+    ManageRedemptionsResponse, Page, PageResponse, RedemptionStatus, RewardFilter,
+    UpdateRewardRequest,
 };
-use reqwest;
+use crate::pagination::Paginator;
+use crate::scopes::Scope;
+use crate::session::Session;
+
+/// Most redemptions Kick accepts in one accept/reject request
+const MAX_REDEMPTION_BATCH: usize = 25;
 
 /// Rewards API - handles all channel reward endpoints
 pub struct RewardsApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
+    idempotency_key: Option<String>,
 }
 
 impl<'a> RewardsApi<'a> {
     /// Create a new RewardsApi instance
-    pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
-        base_url: &'a str,
-    ) -> Self {
+    pub(crate) fn new(client: &'a HttpClient, session: &'a Session, base_url: &'a str) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
+            idempotency_key: None,
         }
     }
 
+    /// Send `key` as the `Idempotency-Key` header on writes sent through
+    /// this instance
+    ///
+    /// Reuse the same key when resending after an error you can't classify.
+    /// With a key, timed out writes and server errors are also retried
+    /// following the client's [`RetryPolicy`](crate::RetryPolicy). Kick
+    /// doesn't document support for the header. Calls that send several requests,
+    /// such as [`accept_redemptions_batched`](Self::accept_redemptions_batched),
+    /// send `{key}-1`, `{key}-2`, ... for their batches.
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
     /// Get all channel rewards
     ///
+    /// Returns every reward on the channel, including disabled and paused
+    /// ones. Use [`get_all_filtered`](Self::get_all_filtered) to narrow the list.
+    ///
     /// Requires OAuth token with `channel:rewards:read` scope
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let rewards = client.rewards().get_all().await?;
     /// for reward in rewards {
     ///     println!("Reward: {} - {} points", reward.title, reward.cost);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn get_all(&self) -> Result<Vec<ChannelReward>> {
-        super::require_token(self.token)?;
+        self.session.require_scope(Scope::ChannelRewardsRead)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards", self.base_url);
         let request = self
             .client
             .get(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
-        let response = crate::http::send_with_retry(self.client, request).await?;
+            .bearer_auth(&token);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+
+        crate::http::parse_data_list(response, "Failed to get rewards").await
+    }
 
-        self.parse_response(response).await
+    /// Get a single channel reward by ID
+    ///
+    /// Kick has no endpoint for fetching one reward, so this fetches the
+    /// channel's reward list and picks the matching one. Returns
+    /// [`KickApiError::NotFound`] if no reward has this ID; see
+    /// [`get_opt`](Self::get_opt).
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let reward = client.rewards().get("01HZ8QJ5R2X6V4K9M3N7P1T0WS").await?;
+    /// println!("{} costs {} points", reward.title, reward.cost);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, reward_id: &str) -> Result<ChannelReward> {
+        self.get_opt(reward_id)
+            .await?
+            .ok_or_else(|| KickApiError::NotFound(format!("reward '{}'", reward_id)))
+    }
+
+    /// Get a single channel reward by ID, or `None` if no reward has this ID
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// if client.rewards().get_opt("01HZ8QJ5R2X6V4K9M3N7P1T0WS").await?.is_none() {
+    ///     println!("Reward was deleted");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_opt(&self, reward_id: &str) -> Result<Option<ChannelReward>> {
+        Ok(self
+            .get_all()
+            .await?
+            .into_iter()
+            .find(|r| r.id == reward_id))
+    }
+
+    /// Get channel rewards matching a filter
+    ///
+    /// Filtering happens client-side after fetching the full list, since Kick
+    /// has no server-side filter for this endpoint. `RewardFilter::default()`
+    /// returns the same rewards as [`get_all`](Self::get_all).
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::RewardFilter;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let active = client
+    ///     .rewards()
+    ///     .get_all_filtered(RewardFilter { include_disabled: false, include_paused: false })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_filtered(&self, filter: RewardFilter) -> Result<Vec<ChannelReward>> {
+        let rewards = self.get_all().await?;
+        Ok(rewards.into_iter().filter(|r| filter.matches(r)).collect())
     }
 
     /// Create a new channel reward
     ///
     /// Requires OAuth token with `channel:rewards:write` scope
     ///
+    /// The request is checked with [`CreateRewardRequest::validate`] first,
+    /// so out-of-range fields fail with `InvalidInput` without a round-trip.
+    ///
     /// # Example
     /// ```no_run
     /// use kick_api::CreateRewardRequest;
     ///
-    /// let request = CreateRewardRequest {
-    ///     title: "Song Request".to_string(),
-    ///     cost: 500,
-    ///     description: Some("Request a song!".to_string()),
-    ///     is_user_input_required: Some(true),
-    ///     ..Default::default()
-    /// };
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let request = CreateRewardRequest::builder("Song Request", 500)
+    ///     .description("Request a song!")
+    ///     .user_input_required(true)
+    ///     .build()?;
     ///
     /// let reward = client.rewards().create(request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn create(&self, request: CreateRewardRequest) -> Result<ChannelReward> {
-        super::require_token(self.token)?;
+        request.validate()?;
+        self.session.require_scope(Scope::ChannelRewardsWrite)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards", self.base_url);
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, self.idempotency_key.as_deref());
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
-        self.parse_single_response(response).await
+        crate::http::parse_data_single(response, "Failed to create reward").await
     }
 
     /// Update an existing reward
     ///
+    /// This is a partial update: only the fields set on the request are sent,
+    /// and Kick leaves every omitted field unchanged. There is no need to
+    /// fetch the reward first and resend its current values. Fields that are
+    /// set are checked with [`UpdateRewardRequest::validate`] before sending.
+    ///
     /// Requires OAuth token with `channel:rewards:write` scope
     ///
     /// # Example
     /// ```no_run
     /// use kick_api::UpdateRewardRequest;
     ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let update = UpdateRewardRequest {
     ///     cost: Some(1000),
     ///     is_paused: Some(true),
@@ -99,52 +208,66 @@ impl<'a> RewardsApi<'a> {
     /// };
     ///
     /// let reward = client.rewards().update("reward_id", update).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn update(
         &self,
         reward_id: &str,
         request: UpdateRewardRequest,
     ) -> Result<ChannelReward> {
-        super::require_token(self.token)?;
+        request.validate()?;
+        self.session.require_scope(Scope::ChannelRewardsWrite)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards/{}", self.base_url, reward_id);
         let request = self
             .client
             .patch(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, self.idempotency_key.as_deref());
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
-        self.parse_single_response(response).await
+        crate::http::parse_data_single(response, "Failed to update reward").await
     }
 
     /// Delete a reward
     ///
     /// Requires OAuth token with `channel:rewards:write` scope
     pub async fn delete(&self, reward_id: &str) -> Result<()> {
-        super::require_token(self.token)?;
+        self.session.require_scope(Scope::ChannelRewardsWrite)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards/{}", self.base_url, reward_id);
         let request = self
             .client
             .delete(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
-        let response = crate::http::send_with_retry(self.client, request).await?;
+            .bearer_auth(&token);
+        let request = crate::http::with_idempotency_key(request, self.idempotency_key.as_deref());
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             Ok(())
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to delete reward: {}",
-                response.status()
-            )))
+            Err(crate::http::error_from_response(response, "Failed to delete reward").await)
         }
     }
 
     /// Get reward redemptions
     ///
+    /// Returns the first page only. Use
+    /// [`paginate_redemptions`](Self::paginate_redemptions) to stream every
+    /// page, [`get_redemptions_page`](Self::get_redemptions_page) to walk
+    /// them by hand, or [`get_all_redemptions`](Self::get_all_redemptions)
+    /// to fetch them all at once.
+    ///
+    /// For new redemptions as they happen, subscribe to the
+    /// `channel.reward.redemption.updated` webhook instead of polling this
+    /// endpoint; see [`RewardRedemptionUpdated`](crate::RewardRedemptionUpdated).
+    ///
     /// Requires OAuth token with `channel:rewards:read` scope
     ///
     /// # Parameters
@@ -155,30 +278,145 @@ impl<'a> RewardsApi<'a> {
         reward_id: Option<&str>,
         status: Option<RedemptionStatus>,
     ) -> Result<Vec<ChannelRewardRedemption>> {
-        super::require_token(self.token)?;
+        Ok(self
+            .get_redemptions_page(reward_id, status, None)
+            .await?
+            .items)
+    }
+
+    /// Get one page of reward redemptions
+    ///
+    /// Pass `None` as `cursor` for the first page, then the previous page's
+    /// `next_cursor` for each following one.
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::RedemptionStatus;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let mut cursor = None;
+    /// loop {
+    ///     let page = client
+    ///         .rewards()
+    ///         .get_redemptions_page(None, Some(RedemptionStatus::Accepted), cursor.as_deref())
+    ///         .await?;
+    ///     for redemption in &page.items {
+    ///         println!("{} by {}", redemption.id, redemption.redeemer.user_id);
+    ///     }
+    ///     match page.next_cursor {
+    ///         Some(next) => cursor = Some(next),
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_redemptions_page(
+        &self,
+        reward_id: Option<&str>,
+        status: Option<RedemptionStatus>,
+        cursor: Option<&str>,
+    ) -> Result<Page<ChannelRewardRedemption>> {
+        self.session.require_scope(Scope::ChannelRewardsRead)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards/redemptions", self.base_url);
         let mut request = self
             .client
             .get(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
+            .bearer_auth(&token);
 
         if let Some(id) = reward_id {
             request = request.query(&[("reward_id", id)]);
         }
 
         if let Some(s) = status {
-            let status_str = match s {
-                RedemptionStatus::Pending => "pending",
-                RedemptionStatus::Accepted => "accepted",
-                RedemptionStatus::Rejected => "rejected",
-            };
-            request = request.query(&[("status", status_str)]);
+            request = request.query(&[("status", s.as_str())]);
+        }
+
+        if let Some(cursor) = cursor {
+            request = request.query(&[("cursor", cursor)]);
+        }
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        if response.status().is_success() {
+            let body = response.text().await?;
+            let resp: PageResponse<ChannelRewardRedemption> = crate::http::parse_json(&body)?;
+            Ok(resp.into())
+        } else {
+            Err(crate::http::error_from_response(response, "Failed to get redemptions").await)
         }
+    }
 
-        let response = crate::http::send_with_retry(self.client, request).await?;
-        self.parse_response(response).await
+    /// Get every reward redemption, following pagination to the end
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::RedemptionStatus;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let accepted = client
+    ///     .rewards()
+    ///     .get_all_redemptions(None, Some(RedemptionStatus::Accepted))
+    ///     .await?;
+    /// println!("{} accepted redemptions", accepted.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_redemptions(
+        &self,
+        reward_id: Option<&str>,
+        status: Option<RedemptionStatus>,
+    ) -> Result<Vec<ChannelRewardRedemption>> {
+        self.paginate_redemptions(reward_id, status)
+            .collect_all()
+            .await
+    }
+
+    /// Stream reward redemptions, fetching pages as they are needed
+    ///
+    /// See [`Paginator`] for how pages are fetched and errors reported.
+    ///
+    /// Requires OAuth token with `channel:rewards:read` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let mut redemptions = client.rewards().paginate_redemptions(None, None);
+    /// while let Some(redemption) = redemptions.next().await {
+    ///     let redemption = redemption?;
+    ///     if redemption.user_input.is_some() {
+    ///         println!("First redemption with input: {}", redemption.id);
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate_redemptions(
+        &self,
+        reward_id: Option<&str>,
+        status: Option<RedemptionStatus>,
+    ) -> Paginator<'a, ChannelRewardRedemption> {
+        let (client, session, base_url) = (self.client, self.session, self.base_url);
+        let reward_id = reward_id.map(str::to_string);
+
+        Paginator::new(move |cursor: Option<String>| {
+            let api = RewardsApi::new(client, session, base_url);
+            let reward_id = reward_id.clone();
+            let status = status.clone();
+            async move {
+                api.get_redemptions_page(reward_id.as_deref(), status, cursor.as_deref())
+                    .await
+            }
+        })
     }
 
     /// Accept pending redemptions
@@ -186,12 +424,15 @@ impl<'a> RewardsApi<'a> {
     /// Requires OAuth token with `channel:rewards:write` scope
     ///
     /// # Parameters
-    /// - `redemption_ids`: List of redemption IDs to accept (1-25)
+    /// - `redemption_ids`: List of redemption IDs to accept (1-25). Use
+    ///   [`accept_redemptions_batched`](Self::accept_redemptions_batched) for
+    ///   longer lists.
     pub async fn accept_redemptions(
         &self,
         redemption_ids: Vec<String>,
     ) -> Result<ManageRedemptionsResponse> {
-        self.manage_redemptions("accept", redemption_ids).await
+        self.manage_redemptions("accept", redemption_ids, self.idempotency_key.as_deref())
+            .await
     }
 
     /// Reject pending redemptions
@@ -199,93 +440,252 @@ impl<'a> RewardsApi<'a> {
     /// Requires OAuth token with `channel:rewards:write` scope
     ///
     /// # Parameters
-    /// - `redemption_ids`: List of redemption IDs to reject (1-25)
+    /// - `redemption_ids`: List of redemption IDs to reject (1-25). Use
+    ///   [`reject_redemptions_batched`](Self::reject_redemptions_batched) for
+    ///   longer lists.
     pub async fn reject_redemptions(
         &self,
         redemption_ids: Vec<String>,
     ) -> Result<ManageRedemptionsResponse> {
-        self.manage_redemptions("reject", redemption_ids).await
+        self.manage_redemptions("reject", redemption_ids, self.idempotency_key.as_deref())
+            .await
     }
 
-    // Helper methods
-
-    async fn parse_response<T: serde::de::DeserializeOwned>(
+    /// Accept any number of redemptions, in batches of 25
+    ///
+    /// Splits `redemption_ids` into requests of at most 25 IDs and merges
+    /// the successes and failures of all of them. Stops at the first request
+    /// error; batches sent before it stay applied.
+    ///
+    /// Requires OAuth token with `channel:rewards:write` scope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, ids: Vec<String>) -> kick_api::Result<()> {
+    /// let result = client.rewards().accept_redemptions_batched(ids).await?;
+    /// for failed in &result.failed {
+    ///     println!("{} failed: {:?}", failed.id, failed.reason);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_redemptions_batched(
         &self,
-        response: reqwest::Response,
-    ) -> Result<Vec<T>> {
-        if response.status().is_success() {
-            let body = response.text().await?;
-
-            #[derive(serde::Deserialize)]
-            struct DataResponse<T> {
-                data: Vec<T>,
-            }
-
-            let resp: DataResponse<T> = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
-
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Request failed: {}",
-                response.status()
-            )))
-        }
+        redemption_ids: Vec<String>,
+    ) -> Result<ManageRedemptionsResponse> {
+        self.manage_in_batches("accept", &redemption_ids).await
     }
 
-    async fn parse_single_response<T: serde::de::DeserializeOwned>(
+    /// Reject any number of redemptions, in batches of 25
+    ///
+    /// The rejecting counterpart of
+    /// [`accept_redemptions_batched`](Self::accept_redemptions_batched).
+    ///
+    /// Requires OAuth token with `channel:rewards:write` scope
+    pub async fn reject_redemptions_batched(
         &self,
-        response: reqwest::Response,
-    ) -> Result<T> {
-        if response.status().is_success() {
-            let body = response.text().await?;
-
-            #[derive(serde::Deserialize)]
-            struct DataResponse<T> {
-                data: T,
-            }
+        redemption_ids: Vec<String>,
+    ) -> Result<ManageRedemptionsResponse> {
+        self.manage_in_batches("reject", &redemption_ids).await
+    }
 
-            let resp: DataResponse<T> = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+    /// Accept every pending redemption of a reward
+    ///
+    /// Fetches every page of the reward's pending queue, then accepts it in
+    /// batches of 25, returning the combined successes and failures of all
+    /// batches. Redemptions that fail are not retried. Stops at the first
+    /// request error; batches sent before it stay applied.
+    ///
+    /// Requires OAuth token with `channel:rewards:read` and
+    /// `channel:rewards:write` scopes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let result = client.rewards().accept_all_pending("reward_id_here").await?;
+    /// println!("Accepted {}, {} failed", result.data.len(), result.failed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn accept_all_pending(&self, reward_id: &str) -> Result<ManageRedemptionsResponse> {
+        self.manage_all_pending("accept", reward_id).await
+    }
 
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Request failed: {}",
-                response.status()
-            )))
-        }
+    /// Reject every pending redemption of a reward
+    ///
+    /// The rejecting counterpart of [`accept_all_pending`](Self::accept_all_pending).
+    ///
+    /// Requires OAuth token with `channel:rewards:read` and
+    /// `channel:rewards:write` scopes
+    pub async fn reject_all_pending(&self, reward_id: &str) -> Result<ManageRedemptionsResponse> {
+        self.manage_all_pending("reject", reward_id).await
     }
 
+    // Helper methods
+
     async fn manage_redemptions(
         &self,
         action: &str,
         redemption_ids: Vec<String>,
+        idempotency_key: Option<&str>,
     ) -> Result<ManageRedemptionsResponse> {
-        super::require_token(self.token)?;
+        check_batch_size(redemption_ids.len())?;
+        self.session.require_scope(Scope::ChannelRewardsWrite)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/channels/rewards/redemptions/{}", self.base_url, action);
-        let request_body = ManageRedemptionsRequest { ids: redemption_ids };
+        let request_body = ManageRedemptionsRequest {
+            ids: redemption_ids,
+        };
 
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap())
+            .bearer_auth(&token)
             .json(&request_body);
-        let response = crate::http::send_with_retry(self.client, request).await?;
+        let request = crate::http::with_idempotency_key(request, idempotency_key);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
 
         if response.status().is_success() {
             let body = response.text().await?;
-            let resp: ManageRedemptionsResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
+            let resp: ManageRedemptionsResponse = crate::http::parse_json(&body)?;
             Ok(resp)
         } else {
-            Err(KickApiError::ApiError(format!(
-                "Failed to {} redemptions: {}",
-                action,
-                response.status()
-            )))
+            Err(crate::http::error_from_response(
+                response,
+                &format!("Failed to {} redemptions", action),
+            )
+            .await)
+        }
+    }
+
+    async fn manage_in_batches(
+        &self,
+        action: &str,
+        redemption_ids: &[String],
+    ) -> Result<ManageRedemptionsResponse> {
+        let mut total = ManageRedemptionsResponse {
+            data: Vec::new(),
+            failed: Vec::new(),
+        };
+        for (i, batch) in redemption_ids.chunks(MAX_REDEMPTION_BATCH).enumerate() {
+            let key = self
+                .idempotency_key
+                .as_ref()
+                .map(|key| format!("{}-{}", key, i + 1));
+            let resp = self
+                .manage_redemptions(action, batch.to_vec(), key.as_deref())
+                .await?;
+            total.data.extend(resp.data);
+            total.failed.extend(resp.failed);
+        }
+        Ok(total)
+    }
+
+    async fn manage_all_pending(
+        &self,
+        action: &str,
+        reward_id: &str,
+    ) -> Result<ManageRedemptionsResponse> {
+        // Collect the whole queue before changing it, so processed
+        // redemptions can't shift the pages still to be fetched
+        let mut seen = HashSet::new();
+        let ids: Vec<String> = self
+            .paginate_redemptions(Some(reward_id), Some(RedemptionStatus::Pending))
+            .collect_all()
+            .await?
+            .into_iter()
+            .map(|r| r.id)
+            .filter(|id| seen.insert(id.clone()))
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(ManageRedemptionsResponse {
+                data: Vec::new(),
+                failed: Vec::new(),
+            });
         }
+        self.manage_in_batches(action, &ids).await
+    }
+}
+
+/// Check a redemption list fits in one accept/reject request
+fn check_batch_size(len: usize) -> Result<()> {
+    if (1..=MAX_REDEMPTION_BATCH).contains(&len) {
+        Ok(())
+    } else {
+        Err(KickApiError::InvalidInput(format!(
+            "Expected 1-{} redemption IDs per request, got {}",
+            MAX_REDEMPTION_BATCH, len
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_batch_size() {
+        assert!(check_batch_size(0).is_err());
+        assert!(check_batch_size(1).is_ok());
+        assert!(check_batch_size(25).is_ok());
+        assert!(check_batch_size(26).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_accept_all_pending_walks_every_page() {
+        use crate::KickApiClient;
+        use std::collections::HashMap;
+        use warp::Filter;
+
+        let redemption = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "redeemed_at": "2024-01-01T00:00:00Z",
+                "redeemer": { "user_id": 1 },
+                "status": "pending",
+            })
+        };
+        let pages = warp::get()
+            .and(warp::path!(
+                "public" / "v1" / "channels" / "rewards" / "redemptions"
+            ))
+            .and(warp::query::<HashMap<String, String>>())
+            .map(move |query: HashMap<String, String>| {
+                let page = match query.get("cursor").map(String::as_str) {
+                    None => serde_json::json!({
+                        "data": [redemption("a"), redemption("b")],
+                        "pagination": { "next_cursor": "page2" },
+                    }),
+                    _ => serde_json::json!({ "data": [redemption("c")] }),
+                };
+                warp::reply::json(&page)
+            });
+        // Every redemption on the first page fails and stays pending
+        let accept = warp::post()
+            .and(warp::path!(
+                "public" / "v1" / "channels" / "rewards" / "redemptions" / "accept"
+            ))
+            .and(warp::body::json())
+            .map(move |body: ManageRedemptionsRequest| {
+                let (failed, ok): (Vec<String>, Vec<String>) =
+                    body.ids.into_iter().partition(|id| id != "c");
+                warp::reply::json(&serde_json::json!({
+                    "data": ok.iter().map(|id| redemption(id)).collect::<Vec<_>>(),
+                    "failed": failed
+                        .iter()
+                        .map(|id| serde_json::json!({ "id": id, "reason": "UNKNOWN" }))
+                        .collect::<Vec<_>>(),
+                }))
+            });
+        let client = KickApiClient::mock_client(pages.or(accept));
+
+        let result = client.rewards().accept_all_pending("reward").await.unwrap();
+        let accepted: Vec<&str> = result.data.iter().map(|r| r.id.as_str()).collect();
+        let failed: Vec<&str> = result.failed.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(accepted, ["c"]);
+        assert_eq!(failed, ["a", "b"]);
     }
 }
@@ -1,24 +1,36 @@
+use crate::api::ChannelsApi;
 use crate::error::{KickApiError, Result};
+use crate::http::HttpClient;
 use crate::models::{TokenIntrospection, User};
-use reqwest;
+use crate::scopes::Scope;
+use crate::session::Session;
+use futures_util::{StreamExt, TryStreamExt};
+
+/// Most user IDs Kick accepts in a single `/users` request
+const MAX_IDS_PER_REQUEST: usize = 50;
+
+/// How many chunked `/users` requests run at the same time
+const MAX_CONCURRENT_REQUESTS: usize = 4;
 
 /// Users API - handles all user-related endpoints
+///
+/// Kick's public API has no endpoint listing the channels where the
+/// authenticated user is a moderator or VIP. Roles are only visible per
+/// channel, for example through the badges on that user's messages in the
+/// channel's live chat (see `ChatSender::highest_role`), so a "where am I a
+/// moderator" view has to be assembled from per-channel checks.
 pub struct UsersApi<'a> {
-    client: &'a reqwest::Client,
-    token: &'a Option<String>,
+    client: &'a HttpClient,
+    session: &'a Session,
     base_url: &'a str,
 }
 
 impl<'a> UsersApi<'a> {
     /// Create a new UsersApi instance
-    pub(crate) fn new(
-        client: &'a reqwest::Client,
-        token: &'a Option<String>,
-        base_url: &'a str,
-    ) -> Self {
+    pub(crate) fn new(client: &'a HttpClient, session: &'a Session, base_url: &'a str) -> Self {
         Self {
             client,
-            token,
+            session,
             base_url,
         }
     }
@@ -27,36 +39,125 @@ impl<'a> UsersApi<'a> {
     ///
     /// If no IDs are provided, returns the authenticated user's information.
     ///
+    /// Kick accepts at most 50 IDs per request, so longer lists are split
+    /// into chunks fetched a few at a time. Results come back in chunk order,
+    /// and the first failing chunk fails the whole call.
+    ///
     /// Requires OAuth token with `user:read` scope
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// // Get specific users
     /// let users = client.users().get(vec![123, 456]).await?;
     ///
     /// // Get current authenticated user
     /// let me = client.users().get_me().await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn get(&self, user_ids: Vec<u64>) -> Result<Vec<User>> {
-        super::require_token(self.token)?;
+        if user_ids.len() <= MAX_IDS_PER_REQUEST {
+            return self.get_chunk(&user_ids).await;
+        }
 
-        let url = format!("{}/users", self.base_url);
-        let mut request = self
-            .client
-            .get(&url)
-            .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
+        let chunks: Vec<Vec<User>> =
+            futures_util::stream::iter(user_ids.chunks(MAX_IDS_PER_REQUEST))
+                .map(|chunk| self.get_chunk(chunk))
+                .buffered(MAX_CONCURRENT_REQUESTS)
+                .try_collect()
+                .await?;
 
-        // If IDs provided, add them as separate query params
-        // Format: ?id=123&id=456 (not comma-separated)
-        if !user_ids.is_empty() {
-            for id in user_ids {
-                request = request.query(&[("id", id)]);
-            }
+        Ok(chunks.into_iter().flatten().collect())
+    }
+
+    /// Get a user by username (channel slug)
+    ///
+    /// Kick's `/users` endpoint only takes numeric IDs, so the username is
+    /// first resolved through the slug-keyed channel lookup, and the
+    /// channel's broadcaster ID is then fetched from `/users`. That is two
+    /// requests per call.
+    ///
+    /// Requires OAuth token with `channel:read` and `user:read` scopes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let user = client.users().get_by_slug("xqc").await?;
+    /// println!("{} has ID {}", user.name, user.user_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_slug(&self, slug: &str) -> Result<User> {
+        self.get_by_slugs(&[slug])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| not_found(slug))
+    }
+
+    /// Get a user by username, or `None` if no channel has this slug
+    ///
+    /// Like [`get_by_slug`](Self::get_by_slug), but a missing user isn't an
+    /// error.
+    ///
+    /// Requires OAuth token with `channel:read` and `user:read` scopes
+    pub async fn get_by_slug_opt(&self, slug: &str) -> Result<Option<User>> {
+        match self.get_by_slug(slug).await {
+            Ok(user) => Ok(Some(user)),
+            Err(KickApiError::NotFound(_)) => Ok(None),
+            Err(e) => Err(e),
         }
+    }
 
-        let response = crate::http::send_with_retry(self.client, request).await?;
-        self.parse_response(response).await
+    /// Get several users by username (channel slug)
+    ///
+    /// Resolves the slugs with
+    /// [`ChannelsApi::get_many`](crate::ChannelsApi::get_many), then fetches
+    /// all users with a single [`get`](Self::get) call. Users are returned in
+    /// the order of `slugs`; any slug that can't be resolved fails the whole
+    /// call.
+    ///
+    /// Requires OAuth token with `channel:read` and `user:read` scopes
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let users = client.users().get_by_slugs(&["xqc", "trainwreckstv"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_by_slugs(&self, slugs: &[&str]) -> Result<Vec<User>> {
+        if slugs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let channels = ChannelsApi::new(self.client, self.session, self.base_url, None)
+            .get_many(slugs)
+            .await?;
+        let ids = slugs
+            .iter()
+            .map(|slug| {
+                channels
+                    .iter()
+                    .find(|channel| channel.slug.eq_ignore_ascii_case(slug))
+                    .map(|channel| u64::from(channel.broadcaster_user_id))
+                    .ok_or_else(|| not_found(slug))
+            })
+            .collect::<Result<Vec<u64>>>()?;
+
+        let users = self.get(ids.clone()).await?;
+        slugs
+            .iter()
+            .zip(ids)
+            .map(|(slug, id)| {
+                users
+                    .iter()
+                    .find(|user| user.user_id == id)
+                    .cloned()
+                    .ok_or_else(|| not_found(slug))
+            })
+            .collect()
     }
 
     /// Get the currently authenticated user's information
@@ -67,8 +168,11 @@ impl<'a> UsersApi<'a> {
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let me = client.users().get_me().await?;
     /// println!("Logged in as: {}", me.name);
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn get_me(&self) -> Result<User> {
         let users = self.get(vec![]).await?;
@@ -89,6 +193,7 @@ impl<'a> UsersApi<'a> {
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let introspection = client.users().introspect_token().await?;
     ///
     /// if introspection.is_active() {
@@ -105,61 +210,75 @@ impl<'a> UsersApi<'a> {
     /// } else {
     ///     println!("Token is invalid");
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn introspect_token(&self) -> Result<TokenIntrospection> {
-        super::require_token(self.token)?;
+        let token = self.session.bearer_token().await?;
 
         let url = format!("{}/token/introspect", self.base_url);
         let request = self
             .client
             .post(&url)
             .header("Accept", "*/*")
-            .bearer_auth(self.token.as_ref().unwrap());
-        let response = crate::http::send_with_retry(self.client, request).await?;
-
-        if response.status().is_success() {
-            let body = response.text().await?;
-
-            #[derive(serde::Deserialize)]
-            struct IntrospectResponse {
-                data: TokenIntrospection,
-            }
-
-            let resp: IntrospectResponse = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
-
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Token introspection failed: {}",
-                response.status()
-            )))
-        }
+            .bearer_auth(&token);
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+
+        crate::http::parse_data_single(response, "Token introspection failed").await
     }
 
     // Helper methods
 
-    async fn parse_response<T: serde::de::DeserializeOwned>(
-        &self,
-        response: reqwest::Response,
-    ) -> Result<Vec<T>> {
-        if response.status().is_success() {
-            let body = response.text().await?;
-
-            #[derive(serde::Deserialize)]
-            struct DataResponse<T> {
-                data: Vec<T>,
-            }
-
-            let resp: DataResponse<T> = serde_json::from_str(&body)
-                .map_err(|e| KickApiError::ApiError(format!("JSON parse error: {}", e)))?;
-
-            Ok(resp.data)
-        } else {
-            Err(KickApiError::ApiError(format!(
-                "Request failed: {}",
-                response.status()
-            )))
+    /// Fetch one request's worth of users
+    async fn get_chunk(&self, user_ids: &[u64]) -> Result<Vec<User>> {
+        self.session.require_scope(Scope::UserRead)?;
+        let token = self.session.bearer_token().await?;
+
+        let url = format!("{}/users", self.base_url);
+        let mut request = self
+            .client
+            .get(&url)
+            .header("Accept", "*/*")
+            .bearer_auth(&token);
+
+        // If IDs provided, add them as separate query params
+        // Format: ?id=123&id=456 (not comma-separated)
+        for id in user_ids {
+            request = request.query(&[("id", id)]);
         }
+
+        let response = crate::http::send_with_retry(self.client, self.session, request).await?;
+        crate::http::parse_data_list(response, "Failed to get users").await
+    }
+}
+
+/// The error for a slug that matched no user
+fn not_found(slug: &str) -> KickApiError {
+    KickApiError::NotFound(format!("user '{}'", slug))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::KickApiClient;
+    use std::sync::{Arc, Mutex};
+    use warp::Filter;
+
+    #[tokio::test]
+    async fn test_get_splits_large_id_lists() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let route = warp::path!("public" / "v1" / "users")
+            .and(warp::query::raw())
+            .map(move |query: String| {
+                seen.lock().unwrap().push(query.matches("id=").count());
+                r#"{"data":[]}"#
+            });
+        let client = KickApiClient::mock_client(route);
+
+        client.users().get((1..=120).collect()).await.unwrap();
+
+        let mut sizes = requests.lock().unwrap().clone();
+        sizes.sort();
+        assert_eq!(sizes, [20, 50, 50]);
     }
 }
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use std::sync::PoisonError;
+use std::time::Instant;
+
+use tokio::sync::OnceCell;
+
+use crate::client::{CachedIntrospection, KickApiClient};
+use crate::error::Result;
+use crate::models::{TokenIntrospection, User};
+use crate::scopes::Scope;
+
+/// Per-request view of the current token and user
+///
+/// Created with [`KickApiClient::auth_context`]. The token is introspected
+/// once up front and the current user is fetched at most once, on first
+/// use, so a handler can run several scope checks and identity lookups
+/// without repeating API calls. Drop it when the request is done.
+///
+/// # Example
+/// ```no_run
+/// use kick_api::scopes::Scope;
+///
+/// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+/// let auth = client.auth_context().await?;
+/// auth.require_scope(Scope::ChatWrite)?;
+/// let me = auth.user().await?;
+/// println!("Acting as {}", me.name);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AuthContext<'a> {
+    client: &'a KickApiClient,
+    introspection: TokenIntrospection,
+    scopes: HashSet<String>,
+    user: OnceCell<User>,
+}
+
+impl<'a> AuthContext<'a> {
+    pub(crate) async fn new(client: &'a KickApiClient) -> Result<Self> {
+        let introspection = client.users().introspect_token().await?;
+        let scopes = introspection.scopes().into_iter().collect();
+
+        *client
+            .introspection_cache()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(CachedIntrospection {
+            fetched_at: Instant::now(),
+            introspection: introspection.clone(),
+        });
+
+        Ok(Self {
+            client,
+            introspection,
+            scopes,
+            user: OnceCell::new(),
+        })
+    }
+
+    /// The token introspection result
+    pub fn introspection(&self) -> &TokenIntrospection {
+        &self.introspection
+    }
+
+    /// Check if the token is active
+    pub fn is_active(&self) -> bool {
+        self.introspection.is_active()
+    }
+
+    /// The token's granted scopes
+    pub fn scopes(&self) -> &HashSet<String> {
+        &self.scopes
+    }
+
+    /// Check if the token has a specific scope
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.contains(scope)
+    }
+
+    /// Fail with [`KickApiError::InsufficientScope`](crate::KickApiError::InsufficientScope)
+    /// unless the token has `scope`
+    pub fn require_scope(&self, scope: Scope) -> Result<()> {
+        crate::session::check_scope(&self.scopes, scope)
+    }
+
+    /// Get the authenticated user, fetching it on first call
+    pub async fn user(&self) -> Result<&User> {
+        self.user
+            .get_or_try_init(|| async { self.client.users().get_me().await })
+            .await
+    }
+}
@@ -1,38 +1,79 @@
-use crate::api::{ChannelsApi, ChatApi, EventsApi, ModerationApi, RewardsApi, UsersApi};
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::api::{
+    CategoriesApi, ChannelsApi, ChatApi, EventsApi, LivestreamsApi, ModerationApi, RewardsApi,
+    SendSlots, UsersApi,
+};
+use crate::auth::AuthContext;
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::models::{Channel, TokenIntrospection, User};
+use crate::oauth::{KickOAuth, OAuthTokenResponse};
+use crate::session::Session;
 
 const KICK_BASE_URL: &str = "https://api.kick.com/public/v1";
 
 /// Main Kick API client
 ///
+/// # Cancellation
+///
+/// The client spawns no background tasks. Every request, including rate
+/// limit retries and their backoff sleeps, runs inside the future returned
+/// by the API method, so dropping that future (e.g. via
+/// `tokio::time::timeout` or `tokio::select!`) cancels the request and frees
+/// its connection. Dropping the last clone of the client closes its pooled
+/// connections; there is nothing to shut down explicitly.
+///
 /// # Example
 /// ```no_run
 /// use kick_api::KickApiClient;
 ///
-/// // Without authentication (limited endpoints)
-/// let client = KickApiClient::new();
+/// # async fn example(oauth: kick_api::KickOAuth) -> kick_api::Result<()> {
+/// // With an app access token, enough for public channel data
+/// let token = oauth.get_app_access_token().await?;
+/// let client = KickApiClient::with_token(token.access_token);
 ///
-/// // With OAuth token
+/// // With a user's OAuth token
 /// let client = KickApiClient::with_token("your_token_here".to_string());
 ///
 /// // Use the API modules
 /// let channel = client.channels().get("xqc").await?;
 /// let rewards = client.rewards().get_all().await?;
+/// # Ok(())
+/// # }
 /// ```
 #[derive(Debug, Clone)]
 pub struct KickApiClient {
     base_url: String,
-    client: reqwest::Client,
-    oauth_token: Option<String>,
+    client: HttpClient,
+    session: Arc<Session>,
+    introspection_cache: IntrospectionCache,
+    send_slots: SendSlots,
+    locale: Option<String>,
+}
+
+/// Shared cache of the most recent token introspection
+pub(crate) type IntrospectionCache = Arc<Mutex<Option<CachedIntrospection>>>;
+
+/// A token introspection result and when it was fetched
+#[derive(Debug)]
+pub(crate) struct CachedIntrospection {
+    pub(crate) fetched_at: Instant,
+    pub(crate) introspection: TokenIntrospection,
 }
 
 impl KickApiClient {
-    /// Create a new client without authentication (for public endpoints only)
+    /// Create a client without a token
+    ///
+    /// Kick's public API has no anonymous access, so API calls fail until a
+    /// token is set with [`set_token`](Self::set_token). For public data an
+    /// app access token from
+    /// [`KickOAuth::get_app_access_token`](crate::KickOAuth::get_app_access_token)
+    /// is enough.
     pub fn new() -> Self {
-        KickApiClient {
-            base_url: KICK_BASE_URL.to_string(),
-            client: reqwest::Client::new(),
-            oauth_token: None,
-        }
+        Self::from_parts(reqwest::Client::new(), Session::new(None))
     }
 
     /// Create a client with OAuth authentication
@@ -40,79 +81,572 @@ impl KickApiClient {
     /// # Parameters
     /// - `token`: Your OAuth access token from the authorization flow
     pub fn with_token(token: String) -> Self {
+        Self::from_parts(reqwest::Client::new(), Session::new(Some(token)))
+    }
+
+    /// Create a client that refreshes its token automatically
+    ///
+    /// Keeps the access and refresh tokens from `token` along with `oauth`.
+    /// The token is refreshed shortly before it expires, and once more if a
+    /// request still comes back 401, after which the request is retried.
+    /// Concurrent requests share a single refresh, and clones of this client
+    /// see the refreshed token.
+    ///
+    /// If `token` has no refresh token, this behaves like
+    /// [`with_token`](Self::with_token).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{KickApiClient, KickOAuth};
+    ///
+    /// # async fn example(code: String, verifier: oauth2::PkceCodeVerifier) -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = KickOAuth::from_env()?;
+    /// let token = oauth.exchange_code(code, verifier).await?;
+    /// let client = KickApiClient::with_oauth(oauth, token);
+    /// let me = client.users().get_me().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_oauth(oauth: KickOAuth, token: OAuthTokenResponse) -> Self {
+        Self::from_parts(reqwest::Client::new(), Session::with_oauth(oauth, token))
+    }
+
+    /// Create a client that sends requests through your own `reqwest::Client`
+    ///
+    /// Use this to configure timeouts, proxies, a user agent or connection
+    /// pooling with `reqwest::ClientBuilder`, or to share one connection pool
+    /// between several clients. Pass `None` to start without a token, as
+    /// with [`new`](Self::new).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use kick_api::KickApiClient;
+    ///
+    /// # fn example() -> kick_api::Result<()> {
+    /// let http = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-bot/1.0")
+    ///     .build()?;
+    /// let client = KickApiClient::with_client(http, Some("your_token_here".to_string()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_client(client: reqwest::Client, token: Option<String>) -> Self {
+        Self::from_parts(client, Session::new(token))
+    }
+
+    fn from_parts(client: reqwest::Client, session: Session) -> Self {
         KickApiClient {
             base_url: KICK_BASE_URL.to_string(),
-            client: reqwest::Client::new(),
-            oauth_token: Some(token),
+            client: HttpClient::new(client),
+            session: Arc::new(session),
+            introspection_cache: Arc::default(),
+            send_slots: Arc::default(),
+            locale: None,
         }
     }
 
+    /// Run `callback` whenever an automatic token refresh fails
+    ///
+    /// Only clients created with [`with_oauth`](Self::with_oauth) refresh
+    /// their token. The request that needed the refresh still fails with the
+    /// same error; the callback is the place to notice it, e.g. to ask the
+    /// user to log in again when
+    /// [`RefreshFailure::is_terminal`](crate::RefreshFailure::is_terminal).
+    /// Shared by clones of this client.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{KickApiClient, KickOAuth};
+    ///
+    /// # fn example(oauth: KickOAuth, token: kick_api::OAuthTokenResponse) {
+    /// let client = KickApiClient::with_oauth(oauth, token).on_refresh_failure(|failure| {
+    ///     if failure.is_terminal() {
+    ///         eprintln!("Kick login expired, please log in again");
+    ///     } else {
+    ///         eprintln!("Token refresh failed, will retry: {}", failure.error());
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn on_refresh_failure(
+        self,
+        callback: impl Fn(&crate::RefreshFailure<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.session.set_on_refresh_failure(Arc::new(callback));
+        self
+    }
+
+    /// The access token currently in use
+    ///
+    /// Changes after an automatic refresh on clients created with
+    /// [`with_oauth`](Self::with_oauth), e.g. to persist it between runs.
+    pub fn access_token(&self) -> Option<String> {
+        self.session.access_token()
+    }
+
+    /// Replace the access token on this client and all its clones
+    ///
+    /// Use this after refreshing a token yourself, so long-lived clients keep
+    /// their connection pool. Pass `None` to drop the token. The cached
+    /// introspection used by [`scopes`](Self::scopes) and the granted scopes
+    /// checked locally are cleared, since they described the old token; call
+    /// [`load_granted_scopes`](Self::load_granted_scopes) again if you check
+    /// scopes locally.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, oauth: kick_api::KickOAuth, refresh_token: String) -> kick_api::Result<()> {
+    /// let token = oauth.refresh_token(&refresh_token).await?;
+    /// client.set_token(Some(token.access_token));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_token(&self, token: Option<String>) {
+        self.session.set_access_token(token);
+        self.session.set_granted_scopes(None);
+        *self
+            .introspection_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Send API requests to a different base URL
+    ///
+    /// Defaults to `https://api.kick.com/public/v1`. Point this at a local
+    /// mock server to test code that uses the client without reaching Kick.
+    /// A trailing slash is ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickApiClient;
+    ///
+    /// let client = KickApiClient::with_token("test_token".to_string())
+    ///     .with_base_url("http://127.0.0.1:8080/public/v1");
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Serve `route` on a local port and return a client pointed at it,
+    /// authenticated with `test_token`
+    #[cfg(test)]
+    pub(crate) fn mock_client<F>(route: F) -> Self
+    where
+        F: warp::Filter<Error = warp::Rejection> + Clone + Send + Sync + 'static,
+        F::Extract: warp::Reply,
+    {
+        let addr = serve_mock(route);
+        Self::with_token("test_token".to_string()).with_base_url(format!("http://{addr}/public/v1"))
+    }
+
+    /// Give up on requests that take longer than `timeout`
+    ///
+    /// Applies to each attempt of every API call, from connecting until the
+    /// response body is read. Timed out attempts are retried like rate
+    /// limited ones when they are safe to resend (GET, PUT and DELETE, or a
+    /// write with an idempotency key; see [`RetryPolicy`](crate::RetryPolicy)).
+    /// If the last attempt also times out, or an unkeyed POST or PATCH times
+    /// out, the call fails with
+    /// [`KickApiError::Timeout`](crate::KickApiError::Timeout). Without
+    /// this, a stalled connection can hang a call indefinitely.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use kick_api::KickApiClient;
+    ///
+    /// let client = KickApiClient::with_token("your_token_here".to_string())
+    ///     .with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.client.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how failed requests are retried
+    ///
+    /// See [`RetryPolicy`](crate::RetryPolicy). The default retries rate
+    /// limited requests, and timed out ones that are safe to resend, 3 times.
+    pub fn with_retry_policy(mut self, policy: crate::RetryPolicy) -> Self {
+        self.client.retry_policy = policy;
+        self
+    }
+
+    /// Rate limit state reported on the most recent API response
+    ///
+    /// Returns `None` until a response carrying rate limit headers has been
+    /// received. Shared by clones of this client, so it reflects requests
+    /// made through any of them. Use it to slow down before hitting a 429.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client.channels().get("xqc").await?;
+    /// if let Some(limit) = client.rate_limit() {
+    ///     println!("{:?} requests left until {:?}", limit.remaining, limit.reset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limit(&self) -> Option<crate::RateLimit> {
+        self.client.rate_limit()
+    }
+
+    /// Request localized data where Kick supports it
+    ///
+    /// Sends `locale` (e.g. `"de"` or `"pt-BR"`) as the `Accept-Language`
+    /// header on channel and category requests, whose responses include
+    /// category names. Kick doesn't document which endpoints honor the header,
+    /// so responses may still come back in the server's default language.
+    /// No header is sent by default.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickApiClient;
+    ///
+    /// let client = KickApiClient::with_token("your_token_here".to_string()).with_locale("de");
+    /// ```
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Check required scopes locally before each request
+    ///
+    /// With the token's granted scopes known, API methods whose endpoint
+    /// needs a scope missing from `scopes` fail with
+    /// [`KickApiError::InsufficientScope`](crate::KickApiError::InsufficientScope)
+    /// without sending the request. Without this (or
+    /// [`load_granted_scopes`](Self::load_granted_scopes)) no scopes are
+    /// checked and Kick's 403 comes back as usual. Shared by clones of this
+    /// client.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickApiClient;
+    /// use kick_api::scopes::Scope;
+    ///
+    /// let client = KickApiClient::with_token("your_token_here".to_string())
+    ///     .with_granted_scopes([Scope::UserRead, Scope::ChatWrite]);
+    /// ```
+    pub fn with_granted_scopes(
+        self,
+        scopes: impl IntoIterator<Item = crate::scopes::Scope>,
+    ) -> Self {
+        let scopes = scopes
+            .into_iter()
+            .map(|scope| scope.as_str().to_string())
+            .collect();
+        self.session.set_granted_scopes(Some(scopes));
+        self
+    }
+
+    /// Access the Categories API
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let results = client.categories().search("chatting").await?;
+    /// let category = client.categories().get(15).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn categories(&self) -> CategoriesApi<'_> {
+        CategoriesApi::new(
+            &self.client,
+            &self.session,
+            &self.base_url,
+            self.locale.as_deref(),
+        )
+    }
+
     /// Access the Channels API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let channel = client.channels().get("xqc").await?;
     /// let my_channels = client.channels().get_mine().await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn channels(&self) -> ChannelsApi<'_> {
-        ChannelsApi::new(&self.client, &self.oauth_token, &self.base_url)
+        ChannelsApi::new(
+            &self.client,
+            &self.session,
+            &self.base_url,
+            self.locale.as_deref(),
+        )
     }
 
     /// Access the Rewards API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, request: kick_api::CreateRewardRequest) -> kick_api::Result<()> {
     /// let rewards = client.rewards().get_all().await?;
     /// let reward = client.rewards().create(request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn rewards(&self) -> RewardsApi<'_> {
-        RewardsApi::new(&self.client, &self.oauth_token, &self.base_url)
+        RewardsApi::new(&self.client, &self.session, &self.base_url)
+    }
+
+    /// Access the Livestreams API
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let streams = client.livestreams().list(Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn livestreams(&self) -> LivestreamsApi<'_> {
+        LivestreamsApi::new(&self.client, &self.session, &self.base_url)
     }
 
     /// Access the Users API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
     /// let me = client.users().get_me().await?;
     /// let users = client.users().get(vec![123, 456]).await?;
     /// let token_info = client.users().introspect_token().await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn users(&self) -> UsersApi<'_> {
-        UsersApi::new(&self.client, &self.oauth_token, &self.base_url)
+        UsersApi::new(&self.client, &self.session, &self.base_url)
     }
 
     /// Access the Chat API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, request: kick_api::SendMessageRequest) -> kick_api::Result<()> {
     /// let response = client.chat().send_message(request).await?;
     /// client.chat().delete_message("msg_id").await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn chat(&self) -> ChatApi<'_> {
-        ChatApi::new(&self.client, &self.oauth_token, &self.base_url)
+        ChatApi::new(
+            &self.client,
+            &self.session,
+            &self.base_url,
+            &self.send_slots,
+        )
     }
 
     /// Access the Moderation API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, ban_request: kick_api::BanRequest, unban_request: kick_api::UnbanRequest) -> kick_api::Result<()> {
     /// client.moderation().ban(ban_request).await?;
     /// client.moderation().unban(unban_request).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn moderation(&self) -> ModerationApi<'_> {
-        ModerationApi::new(&self.client, &self.oauth_token, &self.base_url)
+        ModerationApi::new(&self.client, &self.session, &self.base_url)
     }
 
     /// Access the Events/Webhooks API
     ///
     /// # Example
     /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, request: kick_api::SubscribeRequest) -> kick_api::Result<()> {
     /// let subs = client.events().list(None).await?;
     /// let results = client.events().subscribe(request).await?;
     /// client.events().unsubscribe(vec!["id".to_string()]).await?;
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn events(&self) -> EventsApi<'_> {
-        EventsApi::new(&self.client, &self.oauth_token, &self.base_url)
+        EventsApi::new(&self.client, &self.session, &self.base_url)
+    }
+
+    /// Get a channel by slug
+    ///
+    /// Shortcut for [`channels().get(slug)`](ChannelsApi::get).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let channel = client.get_channel("xqc").await?;
+    /// println!("{}: {:?}", channel.slug, channel.stream_title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_channel(&self, slug: &str) -> Result<Channel> {
+        self.channels().get(slug).await
+    }
+
+    /// Get the authenticated user
+    ///
+    /// Shortcut for [`users().get_me()`](UsersApi::get_me).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let me = client.get_me().await?;
+    /// println!("Logged in as {}", me.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_me(&self) -> Result<User> {
+        self.users().get_me().await
+    }
+
+    /// Check whether the current token will stay valid for at least `duration`
+    ///
+    /// Introspects the token and compares its remaining lifetime against
+    /// `duration`. Returns `false` for inactive tokens. Tokens without an
+    /// expiry are treated as valid. Use this before starting a long job so it
+    /// doesn't fail halfway through with a 401.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// if !client.token_valid_for(Duration::from_secs(30 * 60)).await? {
+    ///     println!("Refresh the token before starting");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn token_valid_for(&self, duration: Duration) -> Result<bool> {
+        let introspection = self.users().introspect_token().await?;
+        if !introspection.is_active() {
+            return Ok(false);
+        }
+
+        Ok(introspection
+            .remaining_lifetime()
+            .is_none_or(|remaining| remaining >= duration))
+    }
+
+    /// Check whether the current token has expired
+    ///
+    /// Introspects the token. Inactive tokens (revoked or unknown to Kick)
+    /// count as expired. Fails with `ApiError` if the client has no token,
+    /// like every authenticated call.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// if client.is_token_expired().await? {
+    ///     println!("Time to refresh");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_token_expired(&self) -> Result<bool> {
+        let introspection = self.users().introspect_token().await?;
+        Ok(!introspection.is_active() || introspection.is_expired())
+    }
+
+    /// Get the scopes granted to the current token
+    ///
+    /// Always introspects the token; use [`scopes`](Self::scopes) to reuse
+    /// a recent result. Fails with `ApiError` if the client has no token,
+    /// like every authenticated call.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let scopes = client.token_scopes().await?;
+    /// println!("Granted: {}", scopes.join(", "));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn token_scopes(&self) -> Result<Vec<String>> {
+        Ok(self.users().introspect_token().await?.scopes())
+    }
+
+    /// Get the token's granted scopes, re-introspecting only when stale
+    ///
+    /// Returns the cached introspection result if it is younger than
+    /// `max_age`, otherwise introspects the token again and caches the
+    /// result. Clones of this client share the cache.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let scopes = client.scopes(Duration::from_secs(300)).await?;
+    /// if scopes.contains("chat:write") {
+    ///     println!("Can send chat messages");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn scopes(&self, max_age: Duration) -> Result<HashSet<String>> {
+        {
+            let cache = self
+                .introspection_cache
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if let Some(cached) = cache.as_ref()
+                && cached.fetched_at.elapsed() < max_age
+            {
+                return Ok(cached.introspection.scopes().into_iter().collect());
+            }
+        }
+
+        let introspection = self.users().introspect_token().await?;
+        let scopes = introspection.scopes().into_iter().collect();
+
+        *self
+            .introspection_cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(CachedIntrospection {
+            fetched_at: Instant::now(),
+            introspection,
+        });
+
+        Ok(scopes)
+    }
+
+    /// Introspect the token and check its granted scopes from now on
+    ///
+    /// Like [`with_granted_scopes`](Self::with_granted_scopes), but takes
+    /// the scopes from [`introspect_token`](crate::UsersApi::introspect_token).
+    /// Call it again after the token changes to pick up its new scopes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// client.load_granted_scopes().await?;
+    /// // Fails locally if the token lacks `channel:rewards:read`
+    /// let rewards = client.rewards().get_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn load_granted_scopes(&self) -> Result<()> {
+        let scopes = self.scopes(Duration::ZERO).await?;
+        self.session.set_granted_scopes(Some(scopes));
+        Ok(())
+    }
+
+    /// Create a per-request [`AuthContext`]
+    ///
+    /// Introspects the token once (refreshing the cache used by
+    /// [`scopes`](Self::scopes)) and defers the current-user lookup until it
+    /// is first needed.
+    pub async fn auth_context(&self) -> Result<AuthContext<'_>> {
+        AuthContext::new(self).await
+    }
+
+    pub(crate) fn introspection_cache(&self) -> &IntrospectionCache {
+        &self.introspection_cache
     }
 }
 
@@ -121,3 +655,333 @@ impl Default for KickApiClient {
         Self::new()
     }
 }
+
+/// Spawn a server for `route` on an ephemeral local port and return its
+/// address
+#[cfg(test)]
+pub(crate) fn serve_mock<F>(route: F) -> std::net::SocketAddr
+where
+    F: warp::Filter<Error = warp::Rejection> + Clone + Send + Sync + 'static,
+    F::Extract: warp::Reply,
+{
+    let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+    addr
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::KickApiError;
+    use warp::Filter;
+
+    #[tokio::test]
+    async fn test_missing_token_fails_the_same_everywhere() {
+        let client = KickApiClient::new();
+        assert!(matches!(
+            client.token_scopes().await,
+            Err(KickApiError::ApiError(_))
+        ));
+        assert!(matches!(
+            client.channels().get("xqc").await,
+            Err(KickApiError::ApiError(_))
+        ));
+    }
+
+    #[test]
+    fn test_set_token_clears_granted_scopes() {
+        use crate::scopes::Scope;
+
+        let client = KickApiClient::with_token("old_token".to_string())
+            .with_granted_scopes([Scope::UserRead]);
+        assert!(client.session.require_scope(Scope::ChatWrite).is_err());
+
+        client.set_token(Some("new_token".to_string()));
+        assert!(client.session.require_scope(Scope::ChatWrite).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_when_enabled() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let route = warp::path!("public" / "v1" / "channels").map(move || {
+            let status = if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                warp::http::StatusCode::SERVICE_UNAVAILABLE
+            } else {
+                warp::http::StatusCode::OK
+            };
+            warp::reply::with_status(r#"{"data":[]}"#, status)
+        });
+        let client = KickApiClient::mock_client(route).with_retry_policy(crate::RetryPolicy {
+            max_retries: 1,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            retry_on_server_errors: true,
+        });
+
+        assert!(client.channels().get_mine().await.unwrap().is_empty());
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    /// A token that `with_oauth` can refresh, expiring in `expires_in` seconds
+    fn refreshable_token(expires_in: u64) -> OAuthTokenResponse {
+        OAuthTokenResponse {
+            access_token: "old_token".to_string(),
+            refresh_token: Some("refresh_token".to_string()),
+            expires_in,
+            scope: String::new(),
+            token_type: "Bearer".to_string(),
+            expires_at: None,
+        }
+    }
+
+    /// Serve `/oauth/token`, handing out `new_token`, and a channels
+    /// endpoint that only accepts `new_token`; returns the address and the
+    /// refresh and channels request counters
+    fn refresh_server() -> (
+        std::net::SocketAddr,
+        Arc<std::sync::atomic::AtomicUsize>,
+        Arc<std::sync::atomic::AtomicUsize>,
+    ) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let requests = Arc::new(AtomicUsize::new(0));
+        let (refresh_count, request_count) = (refreshes.clone(), requests.clone());
+
+        let token = warp::post()
+            .and(warp::path!("oauth" / "token"))
+            .and(warp::body::form::<std::collections::HashMap<String, String>>())
+            .map(move |form: std::collections::HashMap<String, String>| {
+                assert_eq!(
+                    form.get("refresh_token").map(String::as_str),
+                    Some("refresh_token")
+                );
+                refresh_count.fetch_add(1, Ordering::SeqCst);
+                r#"{"access_token":"new_token","expires_in":3600,"token_type":"Bearer"}"#
+            });
+        let channels = warp::path!("public" / "v1" / "channels")
+            .and(warp::header::<String>("authorization"))
+            .map(move |auth: String| {
+                request_count.fetch_add(1, Ordering::SeqCst);
+                if auth == "Bearer new_token" {
+                    warp::reply::with_status(r#"{"data":[]}"#, warp::http::StatusCode::OK)
+                } else {
+                    warp::reply::with_status("", warp::http::StatusCode::UNAUTHORIZED)
+                }
+            });
+        let addr = serve_mock(token.or(channels));
+        (addr, refreshes, requests)
+    }
+
+    fn test_oauth(addr: std::net::SocketAddr) -> KickOAuth {
+        KickOAuth::new(
+            "client_id".to_string(),
+            "client_secret".to_string(),
+            "http://localhost/callback".to_string(),
+        )
+        .unwrap()
+        .with_base_url(format!("http://{addr}"))
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_once_after_401_and_retries() {
+        use std::sync::atomic::Ordering;
+
+        let (addr, refreshes, requests) = refresh_server();
+        let client = KickApiClient::with_oauth(test_oauth(addr), refreshable_token(3600))
+            .with_base_url(format!("http://{addr}/public/v1"));
+
+        // Both calls are rejected with the old token but share one refresh
+        let channels = client.channels();
+        let (first, second) = tokio::join!(channels.get_mine(), channels.get_mine());
+        assert!(first.unwrap().is_empty() && second.unwrap().is_empty());
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(requests.load(Ordering::SeqCst), 4);
+        assert_eq!(client.access_token().as_deref(), Some("new_token"));
+    }
+
+    #[tokio::test]
+    async fn test_refreshes_expiring_token_before_sending() {
+        use std::sync::atomic::Ordering;
+
+        let (addr, refreshes, requests) = refresh_server();
+        // Inside the expiry margin, so it is refreshed up front
+        let client = KickApiClient::with_oauth(test_oauth(addr), refreshable_token(5))
+            .with_base_url(format!("http://{addr}/public/v1"));
+
+        assert!(client.channels().get_mine().await.unwrap().is_empty());
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reports_terminal_refresh_failure() {
+        use std::sync::Mutex;
+
+        let token = warp::path!("oauth" / "token").map(|| {
+            warp::reply::with_status(
+                r#"{"error":"invalid_grant","error_description":"Refresh token revoked"}"#,
+                warp::http::StatusCode::BAD_REQUEST,
+            )
+        });
+        let addr = serve_mock(token);
+
+        let failures = Arc::new(Mutex::new(Vec::new()));
+        let seen = failures.clone();
+        let client = KickApiClient::with_oauth(test_oauth(addr), refreshable_token(5))
+            .with_base_url(format!("http://{addr}/public/v1"))
+            .on_refresh_failure(move |failure| {
+                seen.lock().unwrap().push(failure.is_terminal());
+            });
+
+        let err = client.channels().get_mine().await.unwrap_err();
+        assert!(matches!(err, KickApiError::OAuth { ref error, .. } if error == "invalid_grant"));
+        assert_eq!(*failures.lock().unwrap(), [true]);
+    }
+
+    #[tokio::test]
+    async fn test_base_url_override_reaches_mock_server() {
+        let route = warp::path!("public" / "v1" / "channels").map(|| {
+            warp::reply::with_status(
+                r#"{"message":"Unauthorized"}"#,
+                warp::http::StatusCode::UNAUTHORIZED,
+            )
+        });
+        let addr = serve_mock(route);
+
+        let client = KickApiClient::with_token("test_token".to_string())
+            .with_base_url(format!("http://{addr}/public/v1/"));
+
+        match client.channels().get("xqc").await {
+            Err(KickApiError::Api {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 401);
+                assert_eq!(message.as_deref(), Some("Unauthorized"));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_category_search_pages() {
+        use std::collections::HashMap;
+
+        let route = warp::path!("public" / "v1" / "categories")
+            .and(warp::query::<HashMap<String, String>>())
+            .map(|query: HashMap<String, String>| {
+                assert_eq!(query.get("q").map(String::as_str), Some("just chat"));
+                let body = match query.get("page").map(String::as_str) {
+                    Some("1") => r#"{"data":[{"id":15,"name":"Just Chatting"},{"id":16,"name":"Just Chatting 2"}]}"#,
+                    Some("2") => r#"{"data":[{"id":17,"name":"Just Chatting 3","thumbnail":"https://kick.com/t.webp"}]}"#,
+                    _ => r#"{"data":[]}"#,
+                };
+                warp::reply::with_status(body, warp::http::StatusCode::OK)
+            });
+        let client = KickApiClient::mock_client(route);
+        let categories = client.categories();
+
+        let first = categories.search("just chat").await.unwrap();
+        assert_eq!(first.iter().map(|c| c.id).collect::<Vec<_>>(), [15, 16]);
+        let second = categories.search_page("just chat", 2).await.unwrap();
+        assert_eq!(
+            second[0].thumbnail.as_deref(),
+            Some("https://kick.com/t.webp")
+        );
+        assert!(
+            categories
+                .search_page("just chat", 3)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(matches!(
+            categories.search_page("just chat", 0).await,
+            Err(KickApiError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_opt_maps_404_to_none() {
+        let route = warp::path!("public" / "v1" / "categories" / u32).map(|_| {
+            warp::reply::with_status(
+                r#"{"message":"Not Found"}"#,
+                warp::http::StatusCode::NOT_FOUND,
+            )
+        });
+        let client = KickApiClient::mock_client(route);
+
+        assert!(client.categories().get_opt(15).await.unwrap().is_none());
+        assert!(matches!(
+            client.categories().get(15).await,
+            Err(KickApiError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retried_writes_reuse_idempotency_key() {
+        use std::sync::Mutex;
+
+        let keys = Arc::new(Mutex::new(Vec::new()));
+        let seen = keys.clone();
+        let route = warp::post()
+            .and(warp::path!("public" / "v1" / "moderation" / "bans"))
+            .and(warp::header::optional::<String>("idempotency-key"))
+            .map(move |key: Option<String>| {
+                let mut seen = seen.lock().unwrap();
+                seen.push(key);
+                let status = if seen.len() % 2 == 1 {
+                    warp::http::StatusCode::SERVICE_UNAVAILABLE
+                } else {
+                    warp::http::StatusCode::OK
+                };
+                warp::reply::with_status("", status)
+            });
+        let client = KickApiClient::mock_client(route).with_retry_policy(crate::RetryPolicy {
+            max_retries: 1,
+            base_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+            retry_on_server_errors: true,
+        });
+        let request = crate::BanRequest::permanent(1, 2);
+
+        client
+            .moderation()
+            .with_idempotency_key("ban-2")
+            .ban(request.clone())
+            .await
+            .unwrap();
+        // Without a key the 503 comes back instead of the POST being resent
+        let err = client.moderation().ban(request).await.unwrap_err();
+        assert!(err.is_server_error());
+
+        let keys = keys.lock().unwrap();
+        assert_eq!(
+            *keys,
+            [Some("ban-2".to_string()), Some("ban-2".to_string()), None]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limited_error_keeps_retry_after() {
+        let route = warp::path!("public" / "v1" / "channels").map(|| {
+            warp::reply::with_header(
+                warp::reply::with_status("", warp::http::StatusCode::TOO_MANY_REQUESTS),
+                "Retry-After",
+                "7",
+            )
+        });
+        let client = KickApiClient::mock_client(route).with_retry_policy(crate::RetryPolicy {
+            max_retries: 0,
+            ..crate::RetryPolicy::default()
+        });
+
+        let err = client.channels().get_mine().await.unwrap_err();
+        assert!(err.is_rate_limited());
+        assert!(!err.is_auth_error() && !err.is_server_error());
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(7)));
+    }
+}
@@ -1,24 +1,172 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum KickApiError {
     #[error("HTTP request failed: {0}")]
-    HttpRequestError(#[from] reqwest::Error),
+    HttpRequestError(reqwest::Error),
+
+    /// The request didn't complete within the client's timeout, including
+    /// after retries. See `KickApiClient::with_timeout`.
+    #[error("Request timed out")]
+    Timeout,
 
     #[error("JSON serialization/deserialization error: {0}")]
     JsonError(#[from] serde_json::Error),
 
+    /// A successful response body didn't match the expected shape.
+    ///
+    /// `body` is the raw response, kept so schema changes on Kick's side can
+    /// be diagnosed.
+    #[error("JSON parse error: {source}")]
+    Deserialization {
+        body: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
     #[error("API returned an error: {0}")]
     ApiError(String),
 
+    /// Kick answered with an unsuccessful HTTP status.
+    ///
+    /// `body` is the raw response body and `message` the `message` field
+    /// Kick includes in its JSON error bodies, when present. `retry_after`
+    /// is Kick's `Retry-After` header, when sent.
+    #[error("API returned {status}: {}", message.as_deref().unwrap_or(body))]
+    Api {
+        status: u16,
+        body: String,
+        message: Option<String>,
+        retry_after: Option<Duration>,
+    },
+
+    /// Kick's OAuth server rejected a token request.
+    ///
+    /// `error` is the OAuth error code (e.g. `invalid_grant` for an expired
+    /// or revoked refresh token) and `description` Kick's explanation.
+    #[error("OAuth error {error}: {}", description.as_deref().unwrap_or("no description"))]
+    OAuth {
+        error: String,
+        description: Option<String>,
+    },
+
+    /// The token lacks a scope the endpoint requires.
+    ///
+    /// Only returned when the token's granted scopes are known (see
+    /// `KickApiClient::with_granted_scopes` and `AuthContext::require_scope`);
+    /// the request is not sent.
+    #[error("Missing required scope {required} (granted: {})", granted.join(" "))]
+    InsufficientScope {
+        required: crate::scopes::Scope,
+        granted: Vec<String>,
+    },
+
+    /// Some events in a subscribe request failed; see
+    /// [`SubscribePartialError`](crate::SubscribePartialError).
+    #[error("{0}")]
+    SubscribePartial(crate::models::SubscribePartialError),
+
+    /// The requested resource doesn't exist: Kick answered 404, or a lookup
+    /// matched nothing. Describes what wasn't found.
+    ///
+    /// Single-resource getters have `_opt` variants (e.g.
+    /// `ChannelsApi::get_opt`) that return `Ok(None)` instead.
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    /// The request was refused for legal reasons (HTTP 451), typically a
+    /// regional block. Kick doesn't document a body for these responses, so
+    /// only the failed operation and status are included.
+    #[error("Blocked for legal reasons: {0}")]
+    Blocked(String),
+
     #[error("Unexpected error: {0}")]
     UnexpectedError(String),
 
+    /// A `pusher:error` event received on the live chat connection.
+    ///
+    /// Pusher groups codes by how to recover: 4000-4099 mean the connection
+    /// should not be retried, 4100-4199 (e.g. 4100 over capacity) mean
+    /// reconnect after backing off, and 4200-4299 (e.g. 4201 pong not
+    /// received) mean reconnect immediately.
+    #[error("Pusher error {code:?}: {message}")]
+    PusherError { code: Option<u16>, message: String },
+
     #[error("WebSocket error: {0}")]
     WebSocketError(#[from] tokio_tungstenite::tungstenite::Error),
 }
 
-pub type Result<T> = std::result::Result<T, KickApiError>;
\ No newline at end of file
+impl KickApiError {
+    /// Whether Kick rate limited the request (429), even after retries
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, KickApiError::Api { status: 429, .. })
+    }
+
+    /// How long Kick asked to wait before retrying, from its `Retry-After`
+    /// header
+    ///
+    /// Usually set on rate limited errors; `None` when Kick didn't send the
+    /// header.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// match client.channels().get("xqc").await {
+    ///     Err(e) if e.is_rate_limited() => {
+    ///         let wait = e.retry_after().unwrap_or(std::time::Duration::from_secs(10));
+    ///         tokio::time::sleep(wait).await;
+    ///     }
+    ///     other => println!("{:?}", other?),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            KickApiError::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Whether the token was rejected (401) or lacks permission (403)
+    ///
+    /// Also true for [`InsufficientScope`](Self::InsufficientScope), which
+    /// is caught before the request is sent.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            KickApiError::Api {
+                status: 401 | 403,
+                ..
+            } | KickApiError::InsufficientScope { .. }
+        )
+    }
+
+    /// Whether Kick failed with a server error (5xx)
+    pub fn is_server_error(&self) -> bool {
+        matches!(
+            self,
+            KickApiError::Api {
+                status: 500..=599,
+                ..
+            }
+        )
+    }
+}
+
+impl From<reqwest::Error> for KickApiError {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            KickApiError::Timeout
+        } else {
+            KickApiError::HttpRequestError(err)
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, KickApiError>;
@@ -1,34 +1,367 @@
-use std::time::Duration;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::error::Result;
+use reqwest::StatusCode;
+use reqwest::header::{AUTHORIZATION, HeaderValue};
 
-const MAX_RETRIES: u32 = 3;
+use crate::error::{KickApiError, Result};
+use crate::session::Session;
 
+/// Emit a `tracing` event when the `tracing` feature is enabled, otherwise
+/// nothing
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
+/// How failed requests are retried
+///
+/// Rate limited (429) requests are always retried. Timed out requests are
+/// retried, and 500, 502, 503 and 504 responses with `retry_on_server_errors`,
+/// only when resending is safe: for idempotent methods (GET, PUT, DELETE), or
+/// for writes the caller gave an idempotency key with `with_idempotency_key`
+/// on the API. A timed out or failed POST may already have been applied, and
+/// Kick doesn't document support for the `Idempotency-Key` header, so
+/// retrying an unkeyed write could post a chat message or create a reward
+/// twice. A 429 means Kick rejected the request, so it is safe to resend
+/// whatever the method.
+///
+/// When Kick sends a `Retry-After` header its value is used. Otherwise
+/// retries back off exponentially, `base_delay * 2^(n - 1)` capped at
+/// `max_delay`, with full jitter: the actual wait is random between zero and
+/// that delay, so concurrent requests limited at the same moment don't retry
+/// in lockstep.
+/// Set it on a client with `KickApiClient::with_retry_policy`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use kick_api::{KickApiClient, RetryPolicy};
+///
+/// // Patient background job: retry more, including on Kick outages
+/// let client = KickApiClient::new().with_retry_policy(RetryPolicy {
+///     max_retries: 6,
+///     base_delay: Duration::from_secs(2),
+///     max_delay: Duration::from_secs(60),
+///     retry_on_server_errors: true,
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt
+    pub max_retries: u32,
+
+    /// Delay before the first retry when Kick sends no `Retry-After` header
+    pub base_delay: Duration,
+
+    /// Upper bound for the backoff delay
+    pub max_delay: Duration,
+
+    /// Also retry on 500, 502, 503 and 504 responses, for requests that are
+    /// safe to resend
+    pub retry_on_server_errors: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            retry_on_server_errors: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Backoff delay before retry number `attempt` (starting at 1), before
+    /// jitter
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// The delay actually waited before retry number `attempt`: uniformly
+    /// random between zero and [`delay`](Self::delay)
+    fn jittered_delay(&self, attempt: u32) -> Duration {
+        use std::hash::{BuildHasher, Hasher};
+
+        // RandomState is seeded randomly per instance, which is plenty for
+        // spreading out retries without pulling in a RNG crate
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u32(attempt);
+        let fraction = (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64;
+        self.delay(attempt).mul_f64(fraction)
+    }
+
+    /// Whether a response with `status` should be retried, given whether the
+    /// request is safe to resend after it may have been applied
+    fn is_retryable(&self, status: StatusCode, resendable: bool) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS
+            || (self.retry_on_server_errors
+                && resendable
+                && matches!(
+                    status,
+                    StatusCode::INTERNAL_SERVER_ERROR
+                        | StatusCode::BAD_GATEWAY
+                        | StatusCode::SERVICE_UNAVAILABLE
+                        | StatusCode::GATEWAY_TIMEOUT
+                ))
+    }
+
+    /// The planned delay before each retry, in order
+    ///
+    /// Shows how the policy behaves without sending anything. These are the
+    /// upper bounds of the jittered waits, and a `Retry-After` header from
+    /// Kick overrides the planned delay for that retry.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use kick_api::RetryPolicy;
+    ///
+    /// let schedule = RetryPolicy::default().schedule();
+    /// assert_eq!(schedule, [1, 2, 4].map(Duration::from_secs));
+    /// ```
+    pub fn schedule(&self) -> Vec<Duration> {
+        (1..=self.max_retries)
+            .map(|attempt| self.delay(attempt))
+            .collect()
+    }
+}
+
+/// Rate limit state reported by Kick on the most recent response
+///
+/// Read from the `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+/// `X-RateLimit-Reset` headers. Each field is `None` when Kick didn't send
+/// the header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed in the current window
+    pub limit: Option<u32>,
+
+    /// Requests left in the current window
+    pub remaining: Option<u32>,
+
+    /// When the current window resets
+    pub reset: Option<SystemTime>,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &reqwest::header::HeaderMap, now: SystemTime) -> Option<Self> {
+        let header = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+
+        let limit = header("x-ratelimit-limit").and_then(|v| u32::try_from(v).ok());
+        let remaining = header("x-ratelimit-remaining").and_then(|v| u32::try_from(v).ok());
+        // Either a Unix timestamp or seconds until the reset
+        let reset = header("x-ratelimit-reset").map(|v| {
+            if v >= 1_000_000_000 {
+                UNIX_EPOCH + Duration::from_secs(v)
+            } else {
+                now + Duration::from_secs(v)
+            }
+        });
+
+        if limit.is_none() && remaining.is_none() && reset.is_none() {
+            return None;
+        }
+        Some(Self {
+            limit,
+            remaining,
+            reset,
+        })
+    }
+}
+
+/// Header carrying a write request's idempotency key
+const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// Set the idempotency key header, if a key is given
+pub(crate) fn with_idempotency_key(
+    request: reqwest::RequestBuilder,
+    key: Option<&str>,
+) -> reqwest::RequestBuilder {
+    match key {
+        Some(key) => request.header(IDEMPOTENCY_KEY, key),
+        None => request,
+    }
+}
+
+/// Whether `request` can be sent again after an attempt that may have been
+/// applied: idempotent methods, and writes the caller gave an idempotency key
+fn is_resendable(request: &reqwest::Request) -> bool {
+    request.method().is_idempotent() || request.headers().contains_key(IDEMPOTENCY_KEY)
+}
+
+/// The `reqwest::Client` used for API calls, plus per-client request settings
+///
+/// Derefs to the inner client so API methods can build requests directly.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HttpClient {
+    inner: reqwest::Client,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retry_policy: RetryPolicy,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+impl HttpClient {
+    pub(crate) fn new(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: Arc::default(),
+        }
+    }
+
+    /// Rate limit state from the most recent response that reported one
+    pub(crate) fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers(), SystemTime::now()) {
+            *self
+                .rate_limit
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner) = Some(rate_limit);
+        }
+    }
+}
+
+impl Deref for HttpClient {
+    type Target = reqwest::Client;
+
+    fn deref(&self) -> &reqwest::Client {
+        &self.inner
+    }
+}
+
+/// Send a request, retrying according to the client's [`RetryPolicy`] and
+/// refreshing the token once on 401
+///
+/// The 401 retry only happens when `session` can refresh its token and the
+/// request carried a bearer token.
+///
+/// With the `tracing` feature, each attempt logs its method and URL at debug,
+/// each response its status and latency at info, and each retry its delay at
+/// warn. Headers and bodies are never logged, so tokens stay out of the logs.
 pub(crate) async fn send_with_retry(
-    client: &reqwest::Client,
+    client: &HttpClient,
+    session: &Session,
     request: reqwest::RequestBuilder,
 ) -> Result<reqwest::Response> {
-    let mut current = request.build()?;
+    let mut request = request.build()?;
+    if request.timeout().is_none() {
+        *request.timeout_mut() = client.timeout;
+    }
+    let used_token = bearer_token_of(&request);
+    let retry = match used_token {
+        Some(_) if session.can_refresh() => request.try_clone(),
+        _ => None,
+    };
+
+    let response = send_rate_limited(client, request).await?;
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return Ok(response);
+    }
+    let (Some(mut retry), Some(used_token)) = (retry, used_token) else {
+        return Ok(response);
+    };
+
+    trace_event!(warn, "401 Unauthorized, refreshing token and retrying");
+    let token = session.refresh(&used_token).await?;
+    let value = HeaderValue::from_str(&format!("Bearer {token}"))
+        .map_err(|e| KickApiError::UnexpectedError(format!("Invalid refreshed token: {e}")))?;
+    retry.headers_mut().insert(AUTHORIZATION, value);
+
+    send_rate_limited(client, retry).await
+}
+
+/// The bearer token a request was built with, if any
+fn bearer_token_of(request: &reqwest::Request) -> Option<String> {
+    request
+        .headers()
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(String::from)
+}
+
+async fn send_rate_limited(
+    client: &HttpClient,
+    mut current: reqwest::Request,
+) -> Result<reqwest::Response> {
+    let policy = &client.retry_policy;
+    let max_retries = policy.max_retries;
+    let resendable = is_resendable(&current);
 
-    for attempt in 0..=MAX_RETRIES {
+    for attempt in 0..=max_retries {
         // Clone before executing so we have a copy for the next retry
-        let next = if attempt < MAX_RETRIES {
+        let next = if attempt < max_retries {
             current.try_clone()
         } else {
             None
         };
 
-        let response = client.execute(current).await?;
+        trace_event!(
+            debug,
+            method = %current.method(),
+            url = %current.url(),
+            attempt,
+            "sending request"
+        );
+        #[cfg(feature = "tracing")]
+        let started = std::time::Instant::now();
 
-        if response.status() == 429 && attempt < MAX_RETRIES {
-            let retry_after = response
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse::<u64>().ok())
-                .unwrap_or(1);
+        let response = match client.execute(current).await {
+            Ok(response) => response,
+            Err(e) if e.is_timeout() && resendable && attempt < max_retries => {
+                let delay = policy.jittered_delay(attempt + 1);
+                trace_event!(
+                    warn,
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    "request timed out, retrying"
+                );
+                tokio::time::sleep(delay).await;
+                current = next.ok_or_else(|| {
+                    KickApiError::UnexpectedError(
+                        "request could not be cloned for retry".to_string(),
+                    )
+                })?;
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        client.record_rate_limit(&response);
+        trace_event!(
+            info,
+            status = response.status().as_u16(),
+            latency_ms = started.elapsed().as_millis() as u64,
+            url = %response.url(),
+            "received response"
+        );
+
+        if policy.is_retryable(response.status(), resendable) && attempt < max_retries {
+            let retry_after =
+                retry_after_of(&response).unwrap_or_else(|| policy.jittered_delay(attempt + 1));
+            trace_event!(
+                warn,
+                status = response.status().as_u16(),
+                attempt,
+                delay_ms = retry_after.as_millis() as u64,
+                "retrying request"
+            );
 
-            tokio::time::sleep(Duration::from_secs(retry_after)).await;
+            tokio::time::sleep(retry_after).await;
 
             // Use the cloned request for the next attempt
             current = next.ok_or_else(|| {
@@ -43,3 +376,237 @@ pub(crate) async fn send_with_retry(
 
     unreachable!()
 }
+
+/// The delay requested by a response's `Retry-After` header, if any
+fn retry_after_of(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get("retry-after")?.to_str().ok()?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+/// Parse a `Retry-After` value, given either as seconds or as an HTTP date
+///
+/// Dates in the past mean retrying right away.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let at = crate::time::parse_http_date(value)?;
+    Some(at.duration_since(now).unwrap_or_default())
+}
+
+/// Parse a successful response body.
+///
+/// Failures become [`KickApiError::Deserialization`] carrying the raw body.
+pub(crate) fn parse_json<T: serde::de::DeserializeOwned>(body: &str) -> Result<T> {
+    serde_json::from_str(body).map_err(|source| KickApiError::Deserialization {
+        body: body.to_string(),
+        source,
+    })
+}
+
+/// Unwrap the `{"data": ...}` envelope of a successful response, or build
+/// the error for an unsuccessful one.
+///
+/// `context` describes the operation for the error (e.g. "Failed to get
+/// channel").
+pub(crate) async fn parse_data_single<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+    context: &str,
+) -> Result<T> {
+    if !response.status().is_success() {
+        return Err(error_from_response(response, context).await);
+    }
+
+    #[derive(serde::Deserialize)]
+    struct DataResponse<T> {
+        data: T,
+    }
+
+    let body = response.text().await?;
+    let resp: DataResponse<T> = parse_json(&body)?;
+    Ok(resp.data)
+}
+
+/// Like [`parse_data_single`], for endpoints whose `data` is a list
+pub(crate) async fn parse_data_list<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+    context: &str,
+) -> Result<Vec<T>> {
+    parse_data_single(response, context).await
+}
+
+/// Build the error for an unsuccessful response, consuming its body.
+///
+/// `context` describes the failed operation (e.g. "Failed to get channel").
+pub(crate) async fn error_from_response(
+    response: reqwest::Response,
+    context: &str,
+) -> KickApiError {
+    let status = response.status();
+    let retry_after = retry_after_of(&response);
+    let body = response.text().await.unwrap_or_default();
+
+    let mut err = status_error(status, body, context);
+    if let KickApiError::Api {
+        retry_after: slot, ..
+    } = &mut err
+    {
+        *slot = retry_after;
+    }
+    err
+}
+
+/// Build the error for an unsuccessful response status and body.
+///
+/// 404 Not Found maps to [`KickApiError::NotFound`] and 451 Unavailable For
+/// Legal Reasons to [`KickApiError::Blocked`], both described by `context`;
+/// every other status becomes a
+/// [`KickApiError::Api`] carrying the body and Kick's `message`, if any.
+pub(crate) fn status_error(status: StatusCode, body: String, context: &str) -> KickApiError {
+    if status == StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS {
+        return KickApiError::Blocked(format!("{}: {}", context, status));
+    }
+    if status == StatusCode::NOT_FOUND {
+        return KickApiError::NotFound(format!("{}: {}", context, status));
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ErrorBody {
+        message: Option<String>,
+    }
+
+    let message = serde_json::from_str::<ErrorBody>(&body)
+        .ok()
+        .and_then(|b| b.message)
+        .filter(|m| !m.is_empty());
+
+    KickApiError::Api {
+        status: status.as_u16(),
+        body,
+        message,
+        retry_after: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_schedule() {
+        let policy = RetryPolicy {
+            max_retries: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_millis(1500),
+            retry_on_server_errors: true,
+        };
+        assert_eq!(
+            policy.schedule(),
+            [500, 1000, 1500, 1500].map(Duration::from_millis)
+        );
+        for attempt in 1..=4 {
+            assert!(policy.jittered_delay(attempt) <= policy.delay(attempt));
+        }
+        assert!(policy.is_retryable(StatusCode::BAD_GATEWAY, true));
+        assert!(!policy.is_retryable(StatusCode::BAD_GATEWAY, false));
+        assert!(policy.is_retryable(StatusCode::TOO_MANY_REQUESTS, false));
+        assert!(!policy.is_retryable(StatusCode::NOT_IMPLEMENTED, true));
+
+        let none = RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        };
+        assert!(!none.is_retryable(StatusCode::SERVICE_UNAVAILABLE, true));
+        assert!(none.is_retryable(StatusCode::TOO_MANY_REQUESTS, true));
+        assert!(none.schedule().is_empty());
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue};
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut headers = HeaderMap::new();
+        assert_eq!(RateLimit::from_headers(&headers, now), None);
+
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("100"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("42"));
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("30"));
+        let rate_limit = RateLimit::from_headers(&headers, now).unwrap();
+        assert_eq!(rate_limit.limit, Some(100));
+        assert_eq!(rate_limit.remaining, Some(42));
+        assert_eq!(rate_limit.reset, Some(now + Duration::from_secs(30)));
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000060"));
+        let rate_limit = RateLimit::from_headers(&headers, now).unwrap();
+        assert_eq!(rate_limit.reset, Some(now + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_parse_retry_after_seconds_or_http_date() {
+        let now = crate::time::parse_http_date("Wed, 21 Oct 2015 07:28:00 GMT").unwrap();
+
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn test_status_error_maps_451_to_blocked() {
+        let err = status_error(
+            StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS,
+            String::new(),
+            "Failed to get channel",
+        );
+        assert!(matches!(err, KickApiError::Blocked(_)));
+
+        let body = r#"{"message":"invalid scope","data":null}"#.to_string();
+        match status_error(StatusCode::FORBIDDEN, body.clone(), "Failed to get channel") {
+            KickApiError::Api {
+                status,
+                body: err_body,
+                message,
+                ..
+            } => {
+                assert_eq!(status, 403);
+                assert_eq!(err_body, body);
+                assert_eq!(message.as_deref(), Some("invalid scope"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let err = status_error(StatusCode::NOT_FOUND, "not json".to_string(), "Failed");
+        assert!(matches!(err, KickApiError::NotFound(_)));
+
+        let err = status_error(StatusCode::BAD_REQUEST, "not json".to_string(), "Failed");
+        assert!(matches!(
+            err,
+            KickApiError::Api {
+                status: 400,
+                message: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_json_keeps_body() {
+        let body = r#"{"data":{"id":"not a number"}}"#;
+        match parse_json::<serde_json::Map<String, serde_json::Value>>("[]") {
+            Err(KickApiError::Deserialization { body, .. }) => assert_eq!(body, "[]"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        assert!(parse_json::<serde_json::Value>(body).is_ok());
+    }
+}
@@ -1,14 +1,38 @@
-mod error;
+// `KickApiError::WebSocketError` holds tungstenite's error unboxed, which
+// makes every `Result` large; boxing it would change the public variant
+#![allow(clippy::result_large_err)]
+
+mod api;
+mod auth;
 mod client;
+mod error;
 mod http;
 mod live_chat;
 mod models;
 mod oauth;
-mod api;
+mod pagination;
+pub mod scopes;
+mod session;
+mod time;
+mod tracking;
+mod webhook;
 
-pub use error::{KickApiError, Result};
+pub use api::{
+    CategoriesApi, ChannelsApi, ChatApi, EventsApi, LivestreamsApi, ModerationApi, RewardsApi,
+    UsersApi,
+};
+pub use auth::AuthContext;
 pub use client::KickApiClient;
-pub use live_chat::LiveChatClient;
+pub use error::{KickApiError, Result};
+pub use http::{RateLimit, RetryPolicy};
+pub use live_chat::{CloseReason, ConnectionState, LiveChatClient, ReconnectPolicy, StreamItem};
 pub use models::*;
-pub use oauth::{KickOAuth, OAuthTokenResponse};
-pub use api::{ChannelsApi, ChatApi, EventsApi, ModerationApi, RewardsApi, UsersApi};
\ No newline at end of file
+pub use oauth::{KickOAuth, OAuthTokenResponse, TokenTypeHint};
+pub use pagination::Paginator;
+pub use session::RefreshFailure;
+pub use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+pub use tracking::{CategoryChange, CategoryTracker, FirstChatterTracker};
+pub use webhook::{
+    EVENT_TYPE_HEADER, MESSAGE_ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookDeduplicator,
+    parse_webhook, verify_signature,
+};
@@ -1,14 +1,24 @@
-use futures_util::{SinkExt, StreamExt};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
+
+use futures_util::{SinkExt, Stream, StreamExt};
+use tokio_tungstenite::{
+    connect_async_with_config,
+    tungstenite::{Message, protocol::WebSocketConfig},
+};
 
 use crate::error::{KickApiError, Result};
-use crate::models::live_chat::{LiveChatMessage, PusherEvent, PusherMessage};
+use crate::models::live_chat::{
+    ChatSettings, KickChatEvent, LiveChatMessage, PusherErrorData, PusherEvent, PusherMessage,
+    PusherMessageRef, chatroom_id_from_channel,
+};
+
+const CHAT_MESSAGE_EVENT: &str = "App\\Events\\ChatMessageEvent";
+const CHATROOM_UPDATED_EVENT: &str = "App\\Events\\ChatroomUpdatedEvent";
 
 const PUSHER_URL: &str = "wss://ws-us2.pusher.com/app/32cbd69e4b950bf97679?protocol=7&client=js&version=8.4.0&flash=false";
 
-type WsStream = tokio_tungstenite::WebSocketStream<
-    tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
->;
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
 
 /// Client for receiving live chat messages over Kick's Pusher WebSocket.
 ///
@@ -33,6 +43,107 @@ type WsStream = tokio_tungstenite::WebSocketStream<
 /// ```
 pub struct LiveChatClient {
     ws: WsStream,
+    own_user_id: Option<u64>,
+    chat_settings: Option<ChatSettings>,
+    heartbeat: Option<Duration>,
+    state: ConnectionState,
+    close_reason: Option<CloseReason>,
+}
+
+/// Connection state of a [`LiveChatClient`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Subscribed and receiving events
+    Connected,
+
+    /// The connection was closed by either side or failed
+    Closed,
+}
+
+/// Why the server closed a live chat connection, from its WebSocket close
+/// frame
+///
+/// Pusher uses 4000-4299 codes; see
+/// [`KickApiError::PusherError`](crate::KickApiError::PusherError) for how
+/// the ranges map to reconnect behavior.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseReason {
+    /// WebSocket close code (e.g. 1000 for a normal close)
+    pub code: u16,
+
+    /// Reason text sent with the code, possibly empty
+    pub reason: String,
+}
+
+impl std::fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.reason.is_empty() {
+            write!(f, "{}", self.code)
+        } else {
+            write!(f, "{}: {}", self.code, self.reason)
+        }
+    }
+}
+
+/// Backoff settings for [`LiveChatClient::resilient_stream`].
+///
+/// The delay before reconnect attempt `n` is `initial_delay * 2^(n - 1)`,
+/// capped at `max_delay`.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Give up after this many consecutive failed attempts (`None` = never)
+    pub max_attempts: Option<u32>,
+
+    /// Delay before the first reconnect attempt
+    pub initial_delay: Duration,
+
+    /// Upper bound on the delay between attempts
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: None,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay before reconnect attempt `attempt` (starting at 1)
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+/// An item yielded by [`LiveChatClient::resilient_stream`].
+// Messages are the common case, so they're kept inline rather than boxed
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+pub enum StreamItem {
+    /// A chat message
+    Message(LiveChatMessage),
+
+    /// The connection was (re)established and subscribed
+    Connected,
+
+    /// The connection closed or failed, with a description of why
+    Disconnected(String),
+
+    /// About to make reconnect attempt `attempt` (starting at 1)
+    Reconnecting { attempt: u32 },
+}
+
+/// Internal state of the resilient stream
+enum ResilientState {
+    Connecting { attempt: u32 },
+    Connected { client: Box<LiveChatClient> },
+    Backoff { attempt: u32 },
 }
 
 impl std::fmt::Debug for LiveChatClient {
@@ -51,11 +162,91 @@ impl LiveChatClient {
     /// `https://kick.com/api/v2/channels/{slug}` in a browser and look for
     /// `"chatroom":{"id":`.
     pub async fn connect(chatroom_id: u64) -> Result<Self> {
+        Self::connect_with_config(chatroom_id, WebSocketConfig::default()).await
+    }
+
+    /// Connect to a chatroom with custom WebSocket limits.
+    ///
+    /// [`connect`](Self::connect) uses tungstenite's defaults, which allow
+    /// messages up to 64 MiB and frames up to 16 MiB. Pusher caps event
+    /// payloads at 10 KB, so chat traffic never comes close to that; lowering
+    /// the limits (e.g. to 1 MiB) bounds memory use if the connection ever
+    /// delivers hostile or malformed frames. Messages over the limit end the
+    /// stream with a [`KickApiError::WebSocketError`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{LiveChatClient, WebSocketConfig};
+    ///
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let config = WebSocketConfig::default()
+    ///     .max_message_size(Some(1 << 20))
+    ///     .max_frame_size(Some(1 << 20));
+    /// let mut chat = LiveChatClient::connect_with_config(27670567, config).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_with_config(chatroom_id: u64, config: WebSocketConfig) -> Result<Self> {
+        Self::connect_to(PUSHER_URL, chatroom_id, config).await
+    }
+
+    /// Connect to a chatroom and keep the connection alive with pings.
+    ///
+    /// Shorthand for [`connect`](Self::connect) followed by
+    /// [`with_heartbeat`](Self::with_heartbeat).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use kick_api::LiveChatClient;
+    ///
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let mut chat = LiveChatClient::connect_with_heartbeat(27670567, Duration::from_secs(60)).await?;
+    /// while let Some(msg) = chat.next_message().await? {
+    ///     println!("{}: {}", msg.sender.username, msg.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_with_heartbeat(chatroom_id: u64, interval: Duration) -> Result<Self> {
+        Ok(Self::connect(chatroom_id).await?.with_heartbeat(interval))
+    }
+
+    /// Send a `pusher:ping` whenever the connection has been idle for
+    /// `interval`.
+    ///
+    /// Pusher drops connections that stay silent for about 120 seconds, so
+    /// quiet chatrooms can disconnect while waiting in
+    /// [`next_message`](Self::next_message). With a heartbeat set, the
+    /// `next_*` methods send a ping each time `interval` passes without a
+    /// frame arriving, and swallow the `pusher:pong` reply. Use an interval
+    /// well under 120 seconds.
+    ///
+    /// Pings are sent from within the read calls, not from a background
+    /// task, so nothing needs to be stopped on [`close`](Self::close); keep
+    /// reading (or call [`send_ping`](Self::send_ping) yourself) to keep the
+    /// connection alive.
+    pub fn with_heartbeat(mut self, interval: Duration) -> Self {
+        self.heartbeat = Some(interval);
+        self
+    }
+
+    /// Connect to a chatroom through a specific Pusher WebSocket URL.
+    ///
+    /// Use this to point the client at a local mock Pusher server in tests.
+    /// [`connect`](Self::connect) and
+    /// [`connect_with_config`](Self::connect_with_config) use Kick's public
+    /// Pusher app.
+    pub async fn connect_to(
+        pusher_url: &str,
+        chatroom_id: u64,
+        config: WebSocketConfig,
+    ) -> Result<Self> {
         let channel = format!("chatrooms.{chatroom_id}.v2");
 
-        let (mut ws, _) = connect_async(PUSHER_URL)
+        let (mut ws, _) = connect_async_with_config(pusher_url, Some(config), false)
             .await
-            .map_err(KickApiError::WebSocketError)?;
+            .map_err(KickApiError::from)?;
 
         // Wait for pusher:connection_established
         wait_for_event(&mut ws, "pusher:connection_established").await?;
@@ -70,12 +261,115 @@ impl LiveChatClient {
         });
         ws.send(Message::Text(subscribe.to_string().into()))
             .await
-            .map_err(KickApiError::WebSocketError)?;
+            .map_err(KickApiError::from)?;
 
         // Wait for subscription confirmation
         wait_for_event(&mut ws, "pusher_internal:subscription_succeeded").await?;
 
-        Ok(Self { ws })
+        Ok(Self {
+            ws,
+            own_user_id: None,
+            chat_settings: None,
+            heartbeat: None,
+            state: ConnectionState::Connected,
+            close_reason: None,
+        })
+    }
+
+    /// Stream chat messages from a chatroom, reconnecting automatically.
+    ///
+    /// Yields connection lifecycle events alongside messages, so a single
+    /// loop can both render chat and track connection state. After a
+    /// disconnect the stream yields [`StreamItem::Reconnecting`], waits
+    /// according to `policy`, and connects again. It ends once
+    /// `policy.max_attempts` consecutive attempts have failed.
+    ///
+    /// Messages sent while disconnected are not replayed.
+    ///
+    /// Nothing runs in the background: the connection, reconnect attempts
+    /// and backoff sleeps only progress while the stream is polled, and
+    /// dropping the stream closes the connection.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use kick_api::{LiveChatClient, ReconnectPolicy, StreamItem};
+    ///
+    /// # async fn example() {
+    /// let stream = LiveChatClient::resilient_stream(27670567, ReconnectPolicy::default());
+    /// let mut stream = std::pin::pin!(stream);
+    ///
+    /// while let Some(item) = stream.next().await {
+    ///     match item {
+    ///         StreamItem::Message(msg) => println!("{}: {}", msg.sender.username, msg.content),
+    ///         StreamItem::Connected => println!("connected"),
+    ///         StreamItem::Disconnected(reason) => println!("disconnected: {reason}"),
+    ///         StreamItem::Reconnecting { attempt } => println!("reconnecting (#{attempt})"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn resilient_stream(
+        chatroom_id: u64,
+        policy: ReconnectPolicy,
+    ) -> impl Stream<Item = StreamItem> {
+        let initial = ResilientState::Connecting { attempt: 0 };
+
+        futures_util::stream::unfold(initial, move |state| {
+            let policy = policy.clone();
+            async move {
+                match state {
+                    ResilientState::Connecting { attempt } => {
+                        if attempt > 0 {
+                            tokio::time::sleep(policy.delay(attempt)).await;
+                        }
+                        match Self::connect(chatroom_id).await {
+                            Ok(client) => Some((
+                                StreamItem::Connected,
+                                ResilientState::Connected {
+                                    client: Box::new(client),
+                                },
+                            )),
+                            Err(e) => Some((
+                                StreamItem::Disconnected(e.to_string()),
+                                ResilientState::Backoff {
+                                    attempt: attempt + 1,
+                                },
+                            )),
+                        }
+                    }
+                    ResilientState::Connected { mut client } => match client.next_message().await {
+                        Ok(Some(msg)) => Some((
+                            StreamItem::Message(msg),
+                            ResilientState::Connected { client },
+                        )),
+                        Ok(None) => {
+                            let reason = match client.last_close_reason() {
+                                Some(close) => format!("connection closed ({close})"),
+                                None => "connection closed".to_string(),
+                            };
+                            Some((
+                                StreamItem::Disconnected(reason),
+                                ResilientState::Backoff { attempt: 1 },
+                            ))
+                        }
+                        Err(e) => Some((
+                            StreamItem::Disconnected(e.to_string()),
+                            ResilientState::Backoff { attempt: 1 },
+                        )),
+                    },
+                    ResilientState::Backoff { attempt } => {
+                        if policy.max_attempts.is_some_and(|max| attempt > max) {
+                            return None;
+                        }
+                        Some((
+                            StreamItem::Reconnecting { attempt },
+                            ResilientState::Connecting { attempt },
+                        ))
+                    }
+                }
+            }
+        })
     }
 
     /// Receive the next raw Pusher event.
@@ -83,47 +377,28 @@ impl LiveChatClient {
     /// Returns all events from the subscribed channel (chat messages, pins,
     /// subs, bans, etc.). Automatically handles Pusher-level pings and
     /// internal protocol events. Returns `None` if the connection is closed.
+    ///
+    /// A `pusher:error` event is returned as [`KickApiError::PusherError`].
     pub async fn next_event(&mut self) -> Result<Option<PusherEvent>> {
         loop {
-            let Some(frame) = self.ws.next().await else {
+            let Some(text) = self.next_text().await? else {
                 return Ok(None);
             };
 
-            let frame = frame.map_err(KickApiError::WebSocketError)?;
-
-            let text = match frame {
-                Message::Text(t) => t,
-                Message::Close(_) => return Ok(None),
-                Message::Ping(data) => {
-                    self.ws
-                        .send(Message::Pong(data))
-                        .await
-                        .map_err(KickApiError::WebSocketError)?;
-                    continue;
-                }
-                _ => continue,
-            };
-
             let pusher_msg: PusherMessage = match serde_json::from_str(&text) {
                 Ok(m) => m,
                 Err(_) => continue,
             };
 
-            // Handle Pusher-level pings automatically
-            if pusher_msg.event == "pusher:ping" {
-                let pong = serde_json::json!({ "event": "pusher:pong", "data": {} });
-                self.ws
-                    .send(Message::Text(pong.to_string().into()))
-                    .await
-                    .map_err(KickApiError::WebSocketError)?;
+            if self
+                .handle_protocol_event(&pusher_msg.event, &pusher_msg.data)
+                .await?
+            {
                 continue;
             }
 
-            // Skip internal Pusher protocol events
-            if pusher_msg.event.starts_with("pusher:")
-                || pusher_msg.event.starts_with("pusher_internal:")
-            {
-                continue;
+            if pusher_msg.event == CHATROOM_UPDATED_EVENT {
+                self.record_chat_settings(&pusher_msg.data);
             }
 
             return Ok(Some(PusherEvent {
@@ -134,47 +409,291 @@ impl LiveChatClient {
         }
     }
 
+    /// Receive the next event, parsed into a [`KickChatEvent`].
+    ///
+    /// Like [`next_event`](Self::next_event), but decodes the double-encoded
+    /// payload of known Kick events so they can be matched on directly.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{KickChatEvent, LiveChatClient};
+    ///
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let mut chat = LiveChatClient::connect(27670567).await?;
+    /// while let Some(event) = chat.next_typed_event().await? {
+    ///     match event {
+    ///         KickChatEvent::Message(msg) => println!("{}: {}", msg.sender.username, msg.content),
+    ///         KickChatEvent::UserBanned(ban) => println!("{} was banned", ban.user.username),
+    ///         KickChatEvent::MessageDeleted(deleted) => println!("Deleted {}", deleted.message_id()),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next_typed_event(&mut self) -> Result<Option<KickChatEvent>> {
+        Ok(self.next_event().await?.map(KickChatEvent::from))
+    }
+
     /// Receive the next chat message.
     ///
     /// Blocks until a chat message arrives. Automatically handles Pusher-level
     /// pings and skips non-chat events. Returns `None` if the connection is
-    /// closed.
+    /// closed, and [`KickApiError::PusherError`] on a `pusher:error` event.
+    ///
+    /// This reads frames directly rather than going through
+    /// [`next_event`](Self::next_event), decoding the payload only for chat
+    /// messages and chatroom updates.
     pub async fn next_message(&mut self) -> Result<Option<LiveChatMessage>> {
         loop {
-            let Some(event) = self.next_event().await? else {
+            let Some(text) = self.next_text().await? else {
                 return Ok(None);
             };
 
-            if event.event != "App\\Events\\ChatMessageEvent" {
+            let pusher_msg: PusherMessageRef<'_> = match serde_json::from_str(&text) {
+                Ok(m) => m,
+                Err(_) => continue,
+            };
+
+            if pusher_msg.event == CHATROOM_UPDATED_EVENT {
+                if let Ok(data) = serde_json::from_str::<String>(pusher_msg.data.get()) {
+                    self.record_chat_settings(&data);
+                }
+                continue;
+            }
+
+            if pusher_msg.event != CHAT_MESSAGE_EVENT {
+                self.handle_protocol_event(&pusher_msg.event, pusher_msg.data.get())
+                    .await?;
                 continue;
             }
 
             // Data is double-encoded: outer JSON has `data` as a string
-            let msg: LiveChatMessage = match serde_json::from_str(&event.data) {
+            let Ok(data) = serde_json::from_str::<String>(pusher_msg.data.get()) else {
+                continue;
+            };
+            let mut msg: LiveChatMessage = match serde_json::from_str(&data) {
                 Ok(m) => m,
                 Err(_) => continue,
             };
+            if msg.chatroom_id.is_none() {
+                msg.chatroom_id = pusher_msg
+                    .channel
+                    .as_deref()
+                    .and_then(chatroom_id_from_channel);
+            }
 
             return Ok(Some(msg));
         }
     }
 
+    /// Receive the next chat message not sent by `user_id`.
+    ///
+    /// Useful for bots that both send and read chat: passing the bot's own
+    /// user ID skips its echoed messages, so it can't react to itself.
+    pub async fn next_message_excluding(
+        &mut self,
+        user_id: u64,
+    ) -> Result<Option<LiveChatMessage>> {
+        loop {
+            let Some(msg) = self.next_message().await? else {
+                return Ok(None);
+            };
+
+            if msg.sender.id != user_id {
+                return Ok(Some(msg));
+            }
+        }
+    }
+
+    /// Set the user ID this client sends messages as.
+    ///
+    /// Enables [`is_own_message`](Self::is_own_message). Pass `None` to clear.
+    pub fn set_own_user_id(&mut self, user_id: Option<u64>) {
+        self.own_user_id = user_id;
+    }
+
+    /// Check whether a message was sent by the user set with
+    /// [`set_own_user_id`](Self::set_own_user_id).
+    ///
+    /// Always `false` if no user ID has been set.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::LiveChatClient;
+    ///
+    /// # async fn example(bot_user_id: u64) -> kick_api::Result<()> {
+    /// let mut chat = LiveChatClient::connect(27670567).await?;
+    /// chat.set_own_user_id(Some(bot_user_id));
+    ///
+    /// while let Some(msg) = chat.next_message().await? {
+    ///     if chat.is_own_message(&msg) {
+    ///         continue;
+    ///     }
+    ///     println!("{}: {}", msg.sender.username, msg.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_own_message(&self, msg: &LiveChatMessage) -> bool {
+        self.own_user_id == Some(msg.sender.id)
+    }
+
+    /// The chatroom's current chat settings, if known.
+    ///
+    /// Kick's public API has no endpoint for reading chat settings, so they
+    /// are taken from the `ChatroomUpdatedEvent` Kick broadcasts whenever a
+    /// moderator changes slow, followers-only, subscribers-only or emote-only
+    /// mode. Returns `None` until the first such event arrives on this
+    /// connection. The value is updated by both
+    /// [`next_event`](Self::next_event) and [`next_message`](Self::next_message).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::LiveChatClient;
+    ///
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let mut chat = LiveChatClient::connect(27670567).await?;
+    /// while let Some(msg) = chat.next_message().await? {
+    ///     let slow = chat.chat_settings().is_some_and(|s| s.slow_mode.enabled);
+    ///     println!("{} (slow mode: {slow})", msg.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chat_settings(&self) -> Option<&ChatSettings> {
+        self.chat_settings.as_ref()
+    }
+
     /// Send a Pusher-level ping to keep the connection alive.
     pub async fn send_ping(&mut self) -> Result<()> {
         let ping = serde_json::json!({ "event": "pusher:ping", "data": {} });
         self.ws
             .send(Message::Text(ping.to_string().into()))
             .await
-            .map_err(KickApiError::WebSocketError)?;
+            .map_err(KickApiError::from)?;
         Ok(())
     }
 
+    /// The current connection state
+    ///
+    /// Becomes [`ConnectionState::Closed`] once a receive method returns
+    /// `Ok(None)` or a WebSocket error, including a failed heartbeat ping or
+    /// pong reply, or after [`close`](Self::close).
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// The close code and reason the server sent, if it closed the connection
+    ///
+    /// `None` while connected, and after a close without a close frame (e.g.
+    /// a dropped TCP connection) or one initiated by [`close`](Self::close).
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let mut chat = kick_api::LiveChatClient::connect(27670567).await?;
+    /// while let Some(msg) = chat.next_message().await? {
+    ///     println!("{}: {}", msg.sender.username, msg.content);
+    /// }
+    /// match chat.last_close_reason() {
+    ///     Some(close) => println!("closed by server: {close}"),
+    ///     None => println!("connection dropped"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_close_reason(&self) -> Option<&CloseReason> {
+        self.close_reason.as_ref()
+    }
+
+    /// Read the next text frame, answering WebSocket pings along the way.
+    ///
+    /// Sends a Pusher ping whenever the heartbeat interval passes without a
+    /// frame.
+    ///
+    /// Returns `None` once the connection is closed.
+    async fn next_text(&mut self) -> Result<Option<tokio_tungstenite::tungstenite::Utf8Bytes>> {
+        // Read errors and failed ping/pong writes alike leave the socket unusable
+        let text = self.read_text().await;
+        if text.is_err() {
+            self.state = ConnectionState::Closed;
+        }
+        text
+    }
+
+    async fn read_text(&mut self) -> Result<Option<tokio_tungstenite::tungstenite::Utf8Bytes>> {
+        loop {
+            let frame = match self.heartbeat {
+                Some(interval) => match tokio::time::timeout(interval, self.ws.next()).await {
+                    Ok(frame) => frame,
+                    Err(_) => {
+                        self.send_ping().await?;
+                        continue;
+                    }
+                },
+                None => self.ws.next().await,
+            };
+            let Some(frame) = frame else {
+                self.state = ConnectionState::Closed;
+                return Ok(None);
+            };
+
+            match frame? {
+                Message::Text(t) => return Ok(Some(t)),
+                Message::Close(close) => {
+                    self.state = ConnectionState::Closed;
+                    self.close_reason = close.map(|frame| CloseReason {
+                        code: frame.code.into(),
+                        reason: frame.reason.to_string(),
+                    });
+                    return Ok(None);
+                }
+                Message::Ping(data) => {
+                    self.ws
+                        .send(Message::Pong(data))
+                        .await
+                        .map_err(KickApiError::from)?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Cache the settings from a `ChatroomUpdatedEvent` payload.
+    fn record_chat_settings(&mut self, data: &str) {
+        if let Ok(settings) = serde_json::from_str(data) {
+            self.chat_settings = Some(settings);
+        }
+    }
+
+    /// Handle Pusher protocol events, replying to pings.
+    ///
+    /// Returns `true` if the event was internal to the Pusher protocol and
+    /// should not be surfaced to the caller, or an error for `pusher:error`.
+    async fn handle_protocol_event(&mut self, event: &str, data: &str) -> Result<bool> {
+        if event == "pusher:error" {
+            return Err(pusher_error(data));
+        }
+
+        // Handle Pusher-level pings automatically
+        if event == "pusher:ping" {
+            let pong = serde_json::json!({ "event": "pusher:pong", "data": {} });
+            if let Err(e) = self.ws.send(Message::Text(pong.to_string().into())).await {
+                self.state = ConnectionState::Closed;
+                return Err(e.into());
+            }
+            return Ok(true);
+        }
+
+        // Skip internal Pusher protocol events
+        Ok(event.starts_with("pusher:") || event.starts_with("pusher_internal:"))
+    }
+
     /// Close the WebSocket connection.
     pub async fn close(&mut self) -> Result<()> {
-        self.ws
-            .close(None)
-            .await
-            .map_err(KickApiError::WebSocketError)?;
+        self.state = ConnectionState::Closed;
+        self.ws.close(None).await.map_err(KickApiError::from)?;
         Ok(())
     }
 }
@@ -188,14 +707,14 @@ async fn wait_for_event(ws: &mut WsStream, event_name: &str) -> Result<()> {
             )));
         };
 
-        let frame = frame.map_err(KickApiError::WebSocketError)?;
+        let frame = frame.map_err(KickApiError::from)?;
 
         let text = match frame {
             Message::Text(t) => t,
             Message::Ping(data) => {
                 ws.send(Message::Pong(data))
                     .await
-                    .map_err(KickApiError::WebSocketError)?;
+                    .map_err(KickApiError::from)?;
                 continue;
             }
             _ => continue,
@@ -209,5 +728,165 @@ async fn wait_for_event(ws: &mut WsStream, event_name: &str) -> Result<()> {
         if msg.event == event_name {
             return Ok(());
         }
+
+        if msg.event == "pusher:error" {
+            return Err(pusher_error(&msg.data));
+        }
+    }
+}
+
+/// Build a [`KickApiError::PusherError`] from a `pusher:error` payload.
+///
+/// The payload is usually a JSON object, but may arrive string-encoded like
+/// other Pusher event data.
+fn pusher_error(data: &str) -> KickApiError {
+    let parsed = serde_json::from_str::<PusherErrorData>(data).or_else(|e| {
+        serde_json::from_str::<String>(data)
+            .map_err(|_| e)
+            .and_then(|inner| serde_json::from_str::<PusherErrorData>(&inner))
+    });
+
+    match parsed {
+        Ok(err) => KickApiError::PusherError {
+            code: err.code,
+            message: err.message,
+        },
+        Err(_) => KickApiError::PusherError {
+            code: None,
+            message: data.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pusher_error_payloads() {
+        let object = r#"{"code":4100,"message":"Over capacity"}"#;
+        let encoded = serde_json::to_string(object).unwrap();
+
+        for data in [object, encoded.as_str()] {
+            match pusher_error(data) {
+                KickApiError::PusherError { code, message } => {
+                    assert_eq!(code, Some(4100));
+                    assert_eq!(message, "Over capacity");
+                }
+                other => panic!("unexpected error: {other:?}"),
+            }
+        }
+
+        let envelope: PusherMessage = serde_json::from_str(
+            r#"{"event":"pusher:error","data":{"code":4201,"message":"Pong reply not received"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            pusher_error(&envelope.data),
+            KickApiError::PusherError {
+                code: Some(4201),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_reconnect_delay_doubles_and_caps() {
+        let policy = ReconnectPolicy {
+            max_attempts: None,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(10),
+        };
+
+        let delays: Vec<u64> = (1..=6).map(|n| policy.delay(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 10, 10]);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_pings_idle_connection() {
+        use warp::Filter;
+        use warp::ws::{Message as WarpMessage, Ws};
+
+        let route = warp::ws().map(|ws: Ws| {
+            ws.on_upgrade(|mut socket| async move {
+                let send = |event: &str, data: &str| {
+                    WarpMessage::text(serde_json::json!({ "event": event, "data": data }).to_string())
+                };
+                let _ = socket.send(send("pusher:connection_established", "{}")).await;
+                // Subscription request
+                let _ = socket.next().await;
+                let _ = socket
+                    .send(send("pusher_internal:subscription_succeeded", "{}"))
+                    .await;
+
+                // Stay silent until the client pings, then answer and send a message
+                while let Some(Ok(frame)) = socket.next().await {
+                    if frame.to_str().is_ok_and(|t| t.contains("pusher:ping")) {
+                        let _ = socket.send(send("pusher:pong", "{}")).await;
+                        let message = serde_json::json!({
+                            "id": "after_ping",
+                            "content": "still here",
+                            "type": "message",
+                            "sender": { "id": 1, "username": "u", "identity": { "color": "", "badges": [] } },
+                        });
+                        let _ = socket
+                            .send(send(CHAT_MESSAGE_EVENT, &message.to_string()))
+                            .await;
+                    }
+                }
+            })
+        });
+        let addr = crate::client::serve_mock(route);
+
+        let mut chat =
+            LiveChatClient::connect_to(&format!("ws://{addr}"), 1, WebSocketConfig::default())
+                .await
+                .unwrap()
+                .with_heartbeat(Duration::from_millis(50));
+
+        let msg = tokio::time::timeout(Duration::from_secs(5), chat.next_message())
+            .await
+            .expect("heartbeat ping was never sent")
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.id, "after_ping");
+        chat.close().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_records_close_reason() {
+        use warp::Filter;
+        use warp::ws::{Message as WarpMessage, Ws};
+
+        let route = warp::ws().map(|ws: Ws| {
+            ws.on_upgrade(|mut socket| async move {
+                let send = |event: &str| {
+                    WarpMessage::text(
+                        serde_json::json!({ "event": event, "data": "{}" }).to_string(),
+                    )
+                };
+                let _ = socket.send(send("pusher:connection_established")).await;
+                let _ = socket.next().await;
+                let _ = socket
+                    .send(send("pusher_internal:subscription_succeeded"))
+                    .await;
+                let _ = socket
+                    .send(WarpMessage::close_with(4200u16, "Generic reconnect"))
+                    .await;
+            })
+        });
+        let addr = crate::client::serve_mock(route);
+
+        let mut chat =
+            LiveChatClient::connect_to(&format!("ws://{addr}"), 1, WebSocketConfig::default())
+                .await
+                .unwrap();
+        assert_eq!(chat.state(), ConnectionState::Connected);
+        assert!(chat.next_message().await.unwrap().is_none());
+
+        assert_eq!(chat.state(), ConnectionState::Closed);
+        let close = chat.last_close_reason().unwrap();
+        assert_eq!(close.code, 4200);
+        assert_eq!(close.to_string(), "4200: Generic reconnect");
     }
 }
@@ -1,9 +1,16 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
 use serde::{Deserialize, Serialize};
 
 /// Channel information
 ///
 /// Returned when fetching channel data via the `/channels` endpoint
 ///
+/// Apart from `broadcaster_user_id` and `slug`, every field falls back to
+/// `None` or its default when missing from the response, so trimmed or
+/// older payloads still parse.
+///
 /// # Example Response
 /// ```json
 /// {
@@ -16,6 +23,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Channel {
     /// Number of active subscribers
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
     pub active_subscribers_count: u32,
 
     /// Banner picture URL
@@ -26,6 +34,7 @@ pub struct Channel {
     pub broadcaster_user_id: u32,
 
     /// Number of canceled subscribers
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
     pub canceled_subscribers_count: u32,
 
     /// Current stream category
@@ -48,6 +57,46 @@ pub struct Channel {
     pub stream_title: Option<String>,
 }
 
+impl Channel {
+    /// How long the channel has been live
+    ///
+    /// See [`Stream::uptime`]. Returns `None` if there is no stream info.
+    pub fn uptime(&self) -> Option<Duration> {
+        self.stream.as_ref()?.uptime()
+    }
+
+    /// Whether the channel is live right now
+    pub fn is_live(&self) -> bool {
+        self.stream.as_ref().is_some_and(|stream| stream.is_live)
+    }
+
+    /// Current viewer count, or `None` while offline
+    pub fn viewer_count(&self) -> Option<u32> {
+        self.stream
+            .as_ref()
+            .filter(|stream| stream.is_live)
+            .map(|stream| stream.viewer_count)
+    }
+
+    /// Name of the current stream category, if one is set
+    pub fn category_name(&self) -> Option<&str> {
+        self.category
+            .as_ref()
+            .map(|category| category.name.as_str())
+    }
+}
+
+/// Shows the slug and live status, e.g. `xqc (live, 1234 viewers)` or
+/// `xqc (offline)`
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.viewer_count() {
+            Some(viewers) => write!(f, "{} (live, {} viewers)", self.slug, viewers),
+            None => write!(f, "{} (offline)", self.slug),
+        }
+    }
+}
+
 /// Stream category information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Category {
@@ -70,18 +119,23 @@ pub struct Stream {
     pub custom_tags: Vec<String>,
 
     /// Whether the stream is currently live
+    #[serde(default)]
     pub is_live: bool,
 
     /// Whether the stream is marked as mature content
+    #[serde(default)]
     pub is_mature: bool,
 
     /// Stream key identifier
+    #[serde(default)]
     pub key: String,
 
     /// Stream language code (e.g., "en")
+    #[serde(default)]
     pub language: String,
 
     /// When the stream started (ISO 8601)
+    #[serde(default)]
     pub start_time: String,
 
     /// Stream thumbnail URL
@@ -89,8 +143,130 @@ pub struct Stream {
     pub thumbnail: Option<String>,
 
     /// Stream URL
+    #[serde(default)]
     pub url: String,
 
     /// Current viewer count
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
     pub viewer_count: u32,
 }
+
+impl Stream {
+    /// How long the stream has been live, measured from `start_time`
+    ///
+    /// Returns `None` if the stream is offline or `start_time` can't be
+    /// parsed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+    /// let channel = client.channels().get("xqc").await?;
+    /// match channel.uptime() {
+    ///     Some(uptime) => println!("Live for {} minutes", uptime.as_secs() / 60),
+    ///     None => println!("Offline"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn uptime(&self) -> Option<Duration> {
+        self.uptime_at(SystemTime::now())
+    }
+
+    fn uptime_at(&self, now: SystemTime) -> Option<Duration> {
+        if !self.is_live {
+            return None;
+        }
+        let started = crate::time::parse_rfc3339(&self.start_time)?;
+        Some(now.duration_since(started).unwrap_or_default())
+    }
+}
+
+/// Request body for updating the authenticated user's channel
+///
+/// Only the fields that are set are changed.
+///
+/// # Example
+/// ```
+/// use kick_api::UpdateChannelRequest;
+///
+/// let update = UpdateChannelRequest {
+///     stream_title: Some("Speedrunning all night".to_string()),
+///     category_id: Some(15),
+/// };
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateChannelRequest {
+    /// New stream title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_title: Option<String>,
+
+    /// New stream category, by ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_id: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_viewer_count_accepts_number_or_string() {
+        let fixture = |viewer_count: &str| {
+            format!(
+                r#"{{"is_live":true,"is_mature":false,"key":"","language":"en",
+                    "start_time":"2024-01-01T00:00:00Z","url":"","viewer_count":{viewer_count}}}"#
+            )
+        };
+
+        let stream: Stream = serde_json::from_str(&fixture("1234")).unwrap();
+        assert_eq!(stream.viewer_count, 1234);
+        let stream: Stream = serde_json::from_str(&fixture(r#""1234""#)).unwrap();
+        assert_eq!(stream.viewer_count, 1234);
+    }
+
+    #[test]
+    fn test_channel_tolerates_missing_fields() {
+        let channel: Channel = serde_json::from_str(
+            r#"{"broadcaster_user_id":1,"slug":"xqc","stream":{"is_live":true}}"#,
+        )
+        .unwrap();
+        assert_eq!(channel.active_subscribers_count, 0);
+        assert!(channel.stream_title.is_none());
+        let stream = channel.stream.unwrap();
+        assert!(stream.is_live);
+        assert!(stream.key.is_empty());
+        assert_eq!(stream.viewer_count, 0);
+    }
+
+    #[test]
+    fn test_channel_accessors() {
+        let mut channel: Channel = serde_json::from_str(
+            r#"{"broadcaster_user_id":1,"slug":"xqc","category":{"id":15,"name":"Just Chatting"},
+                "stream":{"is_live":true,"viewer_count":1234}}"#,
+        )
+        .unwrap();
+        assert!(channel.is_live());
+        assert_eq!(channel.viewer_count(), Some(1234));
+        assert_eq!(channel.category_name(), Some("Just Chatting"));
+        assert_eq!(channel.to_string(), "xqc (live, 1234 viewers)");
+
+        channel.stream = None;
+        assert!(!channel.is_live());
+        assert_eq!(channel.to_string(), "xqc (offline)");
+    }
+
+    #[test]
+    fn test_stream_uptime() {
+        let mut stream: Stream = serde_json::from_str(
+            r#"{"is_live":true,"is_mature":false,"key":"","language":"en",
+                "start_time":"2024-01-01T00:00:00Z","url":"","viewer_count":0}"#,
+        )
+        .unwrap();
+        let now = crate::time::parse_rfc3339("2024-01-01T01:30:00Z").unwrap();
+
+        assert_eq!(stream.uptime_at(now), Some(Duration::from_secs(90 * 60)));
+
+        stream.is_live = false;
+        assert_eq!(stream.uptime_at(now), None);
+    }
+}
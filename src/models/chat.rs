@@ -1,13 +1,30 @@
 use serde::{Deserialize, Serialize};
 
+/// Longest chat message Kick accepts, in characters
+pub const MAX_CHAT_MESSAGE_LEN: usize = 500;
+
+/// Who a chat message is sent as
+///
+/// Kick requires a `broadcaster_user_id` for [`User`](Self::User) messages.
+/// [`Bot`](Self::Bot) messages go to the channel of the token's owner and
+/// ignore `broadcaster_user_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageType {
+    /// Sent as the authenticated user, to `broadcaster_user_id`'s chat
+    User,
+    /// Sent as a bot, to the token owner's own chat
+    Bot,
+}
+
 /// Request body for sending a chat message
 ///
 /// # Example
 /// ```
-/// use kick_api::SendMessageRequest;
+/// use kick_api::{MessageType, SendMessageRequest};
 ///
 /// let request = SendMessageRequest {
-///     r#type: "user".to_string(),
+///     r#type: MessageType::User,
 ///     content: "Hello chat!".to_string(),
 ///     broadcaster_user_id: Some(12345),
 ///     reply_to_message_id: None,
@@ -15,8 +32,8 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SendMessageRequest {
-    /// Message type (e.g., "user")
-    pub r#type: String,
+    /// Who the message is sent as
+    pub r#type: MessageType,
 
     /// Message content text
     pub content: String,
@@ -31,7 +48,7 @@ pub struct SendMessageRequest {
 }
 
 /// Response from sending a chat message
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SendMessageResponse {
     /// Whether the message was successfully sent
     pub is_sent: bool,
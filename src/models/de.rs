@@ -0,0 +1,60 @@
+//! Serde helpers for tolerating inconsistent encodings in Kick's JSON
+
+use serde::{Deserialize, Deserializer, de::Error};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Either a JSON number or a numeric string (e.g. `500` or `"500"`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+impl<T> NumberOrString<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn into_number<E: Error>(self) -> Result<T, E> {
+        match self {
+            NumberOrString::Number(n) => Ok(n),
+            NumberOrString::String(s) => s.trim().parse().map_err(E::custom),
+        }
+    }
+}
+
+/// Deserialize an integer that may be encoded as a number or a numeric string
+pub(crate) fn number_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    NumberOrString::<T>::deserialize(deserializer)?.into_number()
+}
+
+/// Like [`number_or_string`], but for optional fields (use with `#[serde(default)]`)
+pub(crate) fn option_number_or_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    Option::<NumberOrString<T>>::deserialize(deserializer)?
+        .map(NumberOrString::into_number)
+        .transpose()
+}
+
+/// Deserialize a field that is usually a JSON-encoded string but may also be
+/// sent as a plain JSON value; non-string values are kept as their JSON text
+pub(crate) fn string_or_json<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    })
+}
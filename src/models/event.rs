@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::{KickApiError, Result};
+use crate::scopes::Scope;
+
 /// An active event subscription
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventSubscription {
     /// Unique subscription identifier
     pub id: String,
@@ -12,7 +15,7 @@ pub struct EventSubscription {
     /// The broadcaster this subscription is for
     pub broadcaster_user_id: u64,
 
-    /// Event type name (e.g., "chat.message.created")
+    /// Event type name (e.g., "chat.message.sent")
     pub event: String,
 
     /// Event version
@@ -28,28 +31,186 @@ pub struct EventSubscription {
     pub updated_at: String,
 }
 
+/// Kick webhook event types
+///
+/// # Example
+/// ```
+/// use kick_api::KickEvent;
+///
+/// assert_eq!(KickEvent::ChatMessageSent.as_str(), "chat.message.sent");
+/// assert_eq!(KickEvent::from_name("channel.followed"), Some(KickEvent::ChannelFollowed));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KickEvent {
+    /// A chat message was sent (`chat.message.sent`)
+    ChatMessageSent,
+    /// Someone followed the channel (`channel.followed`)
+    ChannelFollowed,
+    /// A subscription was renewed (`channel.subscription.renewal`)
+    ChannelSubscriptionRenewal,
+    /// Subscriptions were gifted (`channel.subscription.gifts`)
+    ChannelSubscriptionGifts,
+    /// A new subscription was started (`channel.subscription.new`)
+    ChannelSubscriptionNew,
+    /// A reward redemption changed status (`channel.reward.redemption.updated`)
+    ChannelRewardRedemptionUpdated,
+    /// The stream went live or offline (`livestream.status.updated`)
+    LivestreamStatusUpdated,
+    /// The stream title, category etc. changed (`livestream.metadata.updated`)
+    LivestreamMetadataUpdated,
+    /// A user was banned or timed out (`moderation.banned`)
+    ModerationBanned,
+    /// KICKs were gifted (`kicks.gifted`)
+    KicksGifted,
+}
+
+impl KickEvent {
+    /// Every known event type
+    pub const ALL: [KickEvent; 10] = [
+        KickEvent::ChatMessageSent,
+        KickEvent::ChannelFollowed,
+        KickEvent::ChannelSubscriptionRenewal,
+        KickEvent::ChannelSubscriptionGifts,
+        KickEvent::ChannelSubscriptionNew,
+        KickEvent::ChannelRewardRedemptionUpdated,
+        KickEvent::LivestreamStatusUpdated,
+        KickEvent::LivestreamMetadataUpdated,
+        KickEvent::ModerationBanned,
+        KickEvent::KicksGifted,
+    ];
+
+    /// The event name as used by Kick (e.g. `"chat.message.sent"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KickEvent::ChatMessageSent => "chat.message.sent",
+            KickEvent::ChannelFollowed => "channel.followed",
+            KickEvent::ChannelSubscriptionRenewal => "channel.subscription.renewal",
+            KickEvent::ChannelSubscriptionGifts => "channel.subscription.gifts",
+            KickEvent::ChannelSubscriptionNew => "channel.subscription.new",
+            KickEvent::ChannelRewardRedemptionUpdated => "channel.reward.redemption.updated",
+            KickEvent::LivestreamStatusUpdated => "livestream.status.updated",
+            KickEvent::LivestreamMetadataUpdated => "livestream.metadata.updated",
+            KickEvent::ModerationBanned => "moderation.banned",
+            KickEvent::KicksGifted => "kicks.gifted",
+        }
+    }
+
+    /// Look up an event by its Kick name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.as_str() == name)
+    }
+
+    /// The latest version of this event's payload
+    ///
+    /// Every event Kick currently documents is at version 1.
+    pub fn current_version(&self) -> u32 {
+        1
+    }
+
+    /// The OAuth scope needed to receive this event
+    ///
+    /// Subscribing always needs `events:subscribe`; events carrying
+    /// reward or KICKs data additionally need the matching read scope.
+    pub fn required_scope(&self) -> Scope {
+        match self {
+            KickEvent::ChannelRewardRedemptionUpdated => Scope::ChannelRewardsRead,
+            KickEvent::KicksGifted => Scope::KicksRead,
+            _ => Scope::EventsSubscribe,
+        }
+    }
+}
+
+impl std::fmt::Display for KickEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<KickEvent> for SubscribeEvent {
+    fn from(event: KickEvent) -> Self {
+        SubscribeEvent::from_event(event)
+    }
+}
+
 /// A single event to subscribe to
+///
+/// Build one from a [`KickEvent`] with [`SubscribeEvent::from_event`] to
+/// rule out typos in the name; `name` stays a string so events this crate
+/// doesn't know yet can still be subscribed to.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscribeEvent {
-    /// Event type name (e.g., "chat.message.created")
+    /// Event type name (e.g., "chat.message.sent")
     pub name: String,
 
     /// Event version
     pub version: u32,
 }
 
+impl SubscribeEvent {
+    /// Create an event, rejecting empty names and a zero version
+    ///
+    /// # Example
+    /// ```
+    /// use kick_api::SubscribeEvent;
+    ///
+    /// let event = SubscribeEvent::new("channel.followed", 1).unwrap();
+    /// assert!(SubscribeEvent::new("channel.followed", 0).is_err());
+    /// ```
+    pub fn new(name: impl Into<String>, version: u32) -> Result<Self> {
+        let event = Self {
+            name: name.into(),
+            version,
+        };
+        event.validate()?;
+        Ok(event)
+    }
+
+    /// Subscribe to a known event at its current version
+    ///
+    /// # Example
+    /// ```
+    /// use kick_api::{KickEvent, SubscribeEvent};
+    ///
+    /// let event = SubscribeEvent::from_event(KickEvent::ChatMessageSent);
+    /// assert_eq!(event.name, "chat.message.sent");
+    /// assert_eq!(event.version, 1);
+    /// ```
+    pub fn from_event(event: KickEvent) -> Self {
+        Self {
+            name: event.as_str().to_string(),
+            version: event.current_version(),
+        }
+    }
+
+    /// Check that the event has a name and a nonzero version
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(KickApiError::InvalidInput(
+                "Event name must not be empty".to_string(),
+            ));
+        }
+        if self.version == 0 {
+            return Err(KickApiError::InvalidInput(format!(
+                "Event '{}' has version 0; versions start at 1",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// Request body for creating event subscriptions
 ///
 /// # Example
 /// ```
-/// use kick_api::{SubscribeRequest, SubscribeEvent};
+/// use kick_api::{KickEvent, SubscribeEvent, SubscribeRequest};
 ///
 /// let request = SubscribeRequest {
 ///     broadcaster_user_id: Some(12345),
 ///     method: "webhook".to_string(),
 ///     events: vec![
-///         SubscribeEvent { name: "chat.message.created".to_string(), version: 1 },
-///         SubscribeEvent { name: "channel.followed".to_string(), version: 1 },
+///         SubscribeEvent::from_event(KickEvent::ChatMessageSent),
+///         SubscribeEvent::from_event(KickEvent::ChannelFollowed),
 ///     ],
 /// };
 /// ```
@@ -66,8 +227,21 @@ pub struct SubscribeRequest {
     pub events: Vec<SubscribeEvent>,
 }
 
+impl SubscribeRequest {
+    /// Check that the request lists at least one event and that every event
+    /// is valid
+    pub fn validate(&self) -> Result<()> {
+        if self.events.is_empty() {
+            return Err(KickApiError::InvalidInput(
+                "Subscribe request must include at least one event".to_string(),
+            ));
+        }
+        self.events.iter().try_for_each(SubscribeEvent::validate)
+    }
+}
+
 /// Result of a single event subscription attempt
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubscribeResult {
     /// Event type name
     pub name: String,
@@ -81,3 +255,95 @@ pub struct SubscribeResult {
     /// Error message if subscription failed
     pub error: Option<String>,
 }
+
+/// Some events in a subscribe request could not be subscribed to
+///
+/// Returned (inside [`KickApiError::SubscribePartial`]) by
+/// [`EventsApi::subscribe_checked`](crate::EventsApi::subscribe_checked).
+/// The subscriptions that did succeed are still active.
+#[derive(Debug, Clone)]
+pub struct SubscribePartialError {
+    /// IDs of the subscriptions that were created
+    pub subscribed: Vec<String>,
+
+    /// Results for the events that failed
+    pub failed: Vec<SubscribeResult>,
+}
+
+impl SubscribePartialError {
+    /// Sort subscribe results, returning the IDs if every event succeeded
+    ///
+    /// A result without a subscription ID counts as failed even when Kick
+    /// reports no error for it.
+    pub(crate) fn check(results: Vec<SubscribeResult>) -> Result<Vec<String>> {
+        let mut subscribed = Vec::new();
+        let mut failed = Vec::new();
+        for result in results {
+            match (&result.subscription_id, &result.error) {
+                (Some(id), None) => subscribed.push(id.clone()),
+                _ => failed.push(result),
+            }
+        }
+
+        if failed.is_empty() {
+            Ok(subscribed)
+        } else {
+            Err(KickApiError::SubscribePartial(SubscribePartialError {
+                subscribed,
+                failed,
+            }))
+        }
+    }
+}
+
+impl std::fmt::Display for SubscribePartialError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} event subscription(s) failed:", self.failed.len())?;
+        for result in &self.failed {
+            let reason = result
+                .error
+                .as_deref()
+                .unwrap_or("no subscription ID returned");
+            write!(f, " {} v{} ({})", result.name, result.version, reason)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SubscribePartialError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_partial_error() {
+        let result = |name: &str, id: Option<&str>, error: Option<&str>| SubscribeResult {
+            name: name.to_string(),
+            version: 1,
+            subscription_id: id.map(str::to_string),
+            error: error.map(str::to_string),
+        };
+
+        let ids = SubscribePartialError::check(vec![result("channel.followed", Some("a"), None)]);
+        assert_eq!(ids.unwrap(), ["a"]);
+
+        let results = vec![
+            result("channel.followed", Some("a"), None),
+            result("chat.message.sent", None, Some("forbidden")),
+            result("kicks.gifted", None, None),
+        ];
+        match SubscribePartialError::check(results) {
+            Err(KickApiError::SubscribePartial(partial)) => {
+                assert_eq!(partial.subscribed, ["a"]);
+                assert_eq!(partial.failed.len(), 2);
+                assert!(
+                    partial
+                        .to_string()
+                        .contains("chat.message.sent v1 (forbidden)")
+                );
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
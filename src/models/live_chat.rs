@@ -1,14 +1,42 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::borrow::Cow;
+use std::ops::Range;
 
 /// Pusher wire-format message (outer envelope)
 #[derive(Debug, Clone, Deserialize)]
 pub(crate) struct PusherMessage {
     pub event: String,
+    #[serde(deserialize_with = "super::de::string_or_json")]
     pub data: String,
     #[serde(default)]
     pub channel: Option<String>,
 }
 
+/// Pusher envelope with the payload left undecoded
+///
+/// Lets `next_message` check the event name before decoding the
+/// double-encoded `data` string, which it only does for the events it
+/// handles.
+#[derive(Debug, Deserialize)]
+pub(crate) struct PusherMessageRef<'a> {
+    #[serde(borrow)]
+    pub event: Cow<'a, str>,
+    #[serde(borrow)]
+    pub data: &'a RawValue,
+    #[serde(default, borrow)]
+    pub channel: Option<Cow<'a, str>>,
+}
+
+/// Payload of a `pusher:error` event
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct PusherErrorData {
+    #[serde(default)]
+    pub code: Option<u16>,
+    #[serde(default)]
+    pub message: String,
+}
+
 /// A raw Pusher event received from the WebSocket.
 ///
 /// Useful for debugging or handling event types beyond chat messages.
@@ -22,13 +50,297 @@ pub struct PusherEvent {
     pub data: String,
 }
 
+impl PusherEvent {
+    /// The chatroom this event was received on
+    ///
+    /// Parsed from the channel name (e.g. `chatrooms.123.v2` gives `123`).
+    /// Returns `None` for events that aren't on a chatroom channel.
+    pub fn chatroom_id(&self) -> Option<u64> {
+        self.channel.as_deref().and_then(chatroom_id_from_channel)
+    }
+
+    /// Parse this event as a [`GiftedSubscriptions`] event
+    ///
+    /// Returns `None` if this is a different event or the payload doesn't
+    /// parse.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::LiveChatClient;
+    ///
+    /// # async fn example() -> kick_api::Result<()> {
+    /// let mut chat = LiveChatClient::connect(27670567).await?;
+    /// while let Some(event) = chat.next_event().await? {
+    ///     if let Some(gifts) = event.gifted_subscriptions() {
+    ///         let from = gifts.gifter().unwrap_or("an anonymous gifter");
+    ///         for recipient in gifts.recipients() {
+    ///             println!("{recipient} got a sub from {from}!");
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn gifted_subscriptions(&self) -> Option<GiftedSubscriptions> {
+        if self.event != GiftedSubscriptions::EVENT {
+            return None;
+        }
+        serde_json::from_str(&self.data).ok()
+    }
+}
+
+/// Subscriptions gifted in a chatroom (`GiftedSubscriptionsEvent`)
+///
+/// Kick's live event only carries usernames, not full sender identities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftedSubscriptions {
+    /// The chatroom the subs were gifted in
+    #[serde(default)]
+    pub chatroom_id: Option<u64>,
+
+    /// Usernames of everyone who received a sub, one per gifted sub
+    #[serde(default)]
+    pub gifted_usernames: Vec<String>,
+
+    /// Username of the gifter, `None` or empty when gifted anonymously
+    #[serde(default)]
+    pub gifter_username: Option<String>,
+
+    /// Total subs this gifter has gifted in the channel, if provided
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub gifter_total: Option<u32>,
+}
+
+impl GiftedSubscriptions {
+    /// Pusher event name for gifted subscriptions
+    pub const EVENT: &'static str = "App\\Events\\GiftedSubscriptionsEvent";
+
+    /// Iterate over the recipients' usernames
+    pub fn recipients(&self) -> impl Iterator<Item = &str> {
+        self.gifted_usernames.iter().map(String::as_str)
+    }
+
+    /// Number of subs gifted in this event
+    pub fn count(&self) -> usize {
+        self.gifted_usernames.len()
+    }
+
+    /// The gifter's username, or `None` for an anonymous gift
+    pub fn gifter(&self) -> Option<&str> {
+        self.gifter_username
+            .as_deref()
+            .filter(|name| !name.is_empty())
+    }
+
+    /// Whether the gifter chose to stay anonymous
+    pub fn is_anonymous(&self) -> bool {
+        self.gifter().is_none()
+    }
+}
+
+/// A new or renewed subscription announced in chat (`SubscriptionEvent`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSubscription {
+    /// The chatroom the subscription was announced in
+    #[serde(default)]
+    pub chatroom_id: Option<u64>,
+
+    /// Username of the subscriber
+    pub username: String,
+
+    /// Total months subscribed, if provided
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub months: Option<u32>,
+}
+
+impl ChatSubscription {
+    /// Pusher event name for subscriptions
+    pub const EVENT: &'static str = "App\\Events\\SubscriptionEvent";
+}
+
+/// A user referenced by a moderation event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatUser {
+    /// Unique user identifier
+    pub id: u64,
+
+    /// Display username
+    pub username: String,
+
+    /// URL-friendly username slug
+    #[serde(default)]
+    pub slug: Option<String>,
+}
+
+/// A user was banned or timed out (`UserBannedEvent`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserBanned {
+    /// Unique ban identifier
+    #[serde(default)]
+    pub id: String,
+
+    /// The banned user
+    pub user: ChatUser,
+
+    /// The moderator who issued the ban
+    #[serde(default)]
+    pub banned_by: Option<ChatUser>,
+
+    /// Whether the ban is permanent rather than a timeout
+    #[serde(default)]
+    pub permanent: bool,
+
+    /// Timeout length in minutes, for timeouts
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub duration: Option<u32>,
+
+    /// When a timeout ends (ISO 8601)
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+impl UserBanned {
+    /// Pusher event name for bans
+    pub const EVENT: &'static str = "App\\Events\\UserBannedEvent";
+}
+
+/// A chat message was deleted (`MessageDeletedEvent`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageDeleted {
+    /// Unique identifier of the deletion event
+    #[serde(default)]
+    pub id: String,
+
+    /// The deleted message
+    pub message: DeletedMessage,
+
+    /// Whether Kick's automatic moderation removed the message
+    #[serde(default, rename = "aiModerated")]
+    pub ai_moderated: bool,
+}
+
+impl MessageDeleted {
+    /// Pusher event name for deleted messages
+    pub const EVENT: &'static str = "App\\Events\\MessageDeletedEvent";
+
+    /// ID of the deleted message
+    pub fn message_id(&self) -> &str {
+        &self.message.id
+    }
+}
+
+/// Reference to a deleted message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeletedMessage {
+    /// Unique message identifier
+    pub id: String,
+}
+
+/// A message was pinned in chat (`PinnedMessageCreatedEvent`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedMessage {
+    /// The pinned message
+    pub message: LiveChatMessage,
+
+    /// How long the message stays pinned, in seconds
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub duration: Option<u32>,
+
+    /// The user who pinned the message
+    #[serde(default, rename = "pinnedBy")]
+    pub pinned_by: Option<ChatUser>,
+}
+
+impl PinnedMessage {
+    /// Pusher event name for pinned messages
+    pub const EVENT: &'static str = "App\\Events\\PinnedMessageCreatedEvent";
+}
+
+/// A typed live chat event
+///
+/// Returned by [`LiveChatClient::next_typed_event`](crate::LiveChatClient::next_typed_event),
+/// or built from a raw [`PusherEvent`] with `KickChatEvent::from`. Events
+/// this crate doesn't model, and known events whose payload doesn't parse,
+/// come back as [`Unknown`](Self::Unknown) with the raw data.
+#[derive(Debug, Clone)]
+pub enum KickChatEvent {
+    /// A chat message (`ChatMessageEvent`)
+    Message(LiveChatMessage),
+    /// A new or renewed subscription (`SubscriptionEvent`)
+    Subscription(ChatSubscription),
+    /// Gifted subscriptions (`GiftedSubscriptionsEvent`)
+    GiftedSubscriptions(GiftedSubscriptions),
+    /// A ban or timeout (`UserBannedEvent`)
+    UserBanned(UserBanned),
+    /// A deleted message (`MessageDeletedEvent`)
+    MessageDeleted(MessageDeleted),
+    /// A pinned message (`PinnedMessageCreatedEvent`)
+    PinnedMessage(PinnedMessage),
+    /// Changed chat settings (`ChatroomUpdatedEvent`)
+    ChatroomUpdated(ChatSettings),
+    /// Any other event
+    Unknown {
+        /// The Pusher event name
+        event: String,
+        /// The raw JSON data payload
+        data: String,
+    },
+}
+
+impl From<PusherEvent> for KickChatEvent {
+    fn from(event: PusherEvent) -> Self {
+        fn parse<T: serde::de::DeserializeOwned>(data: &str) -> Option<T> {
+            serde_json::from_str(data).ok()
+        }
+
+        let typed = match event.event.as_str() {
+            "App\\Events\\ChatMessageEvent" => {
+                parse::<LiveChatMessage>(&event.data).map(|mut msg| {
+                    if msg.chatroom_id.is_none() {
+                        msg.chatroom_id = event.chatroom_id();
+                    }
+                    KickChatEvent::Message(msg)
+                })
+            }
+            ChatSubscription::EVENT => parse(&event.data).map(KickChatEvent::Subscription),
+            GiftedSubscriptions::EVENT => {
+                parse(&event.data).map(KickChatEvent::GiftedSubscriptions)
+            }
+            UserBanned::EVENT => parse(&event.data).map(KickChatEvent::UserBanned),
+            MessageDeleted::EVENT => parse(&event.data).map(KickChatEvent::MessageDeleted),
+            PinnedMessage::EVENT => parse(&event.data).map(KickChatEvent::PinnedMessage),
+            "App\\Events\\ChatroomUpdatedEvent" => {
+                parse(&event.data).map(KickChatEvent::ChatroomUpdated)
+            }
+            _ => None,
+        };
+
+        typed.unwrap_or(KickChatEvent::Unknown {
+            event: event.event,
+            data: event.data,
+        })
+    }
+}
+
+/// Parse the chatroom ID out of a Pusher channel name like `chatrooms.123.v2`
+pub(crate) fn chatroom_id_from_channel(channel: &str) -> Option<u64> {
+    let rest = channel
+        .strip_prefix("chatrooms.")
+        .or_else(|| channel.strip_prefix("chatroom_"))?;
+    rest.split('.').next()?.parse().ok()
+}
+
 /// A live chat message received over the Pusher WebSocket
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiveChatMessage {
     /// Unique message identifier
     pub id: String,
 
-    /// The chatroom this message was sent in (may not be present in all payloads)
+    /// The chatroom this message was sent in
+    ///
+    /// Messages read through [`LiveChatClient`](crate::LiveChatClient) fall
+    /// back to the chatroom in the Pusher channel name when the payload
+    /// omits it, so this is always set for them.
     #[serde(default)]
     pub chatroom_id: Option<u64>,
 
@@ -51,8 +363,258 @@ pub struct LiveChatMessage {
     pub metadata: Option<ChatMessageMetadata>,
 }
 
+impl LiveChatMessage {
+    /// Emotes embedded in the message content
+    ///
+    /// Kick writes emotes inline as `[emote:12345:name]` tokens. Each
+    /// [`Emote`] carries the byte range of its token in `content`, in order
+    /// of appearance. Malformed tokens are left as text.
+    ///
+    /// # Example
+    /// ```
+    /// # fn example(msg: kick_api::LiveChatMessage) {
+    /// for emote in msg.parse_emotes() {
+    ///     println!("{} -> https://files.kick.com/emotes/{}/fullsize", emote.name, emote.id);
+    /// }
+    /// # }
+    /// ```
+    pub fn parse_emotes(&self) -> Vec<Emote> {
+        parse_emotes(&self.content)
+    }
+
+    /// The message content with emote tokens removed
+    ///
+    /// Whitespace left behind by removed emotes is collapsed to single
+    /// spaces, so `"hi [emote:1:wave] there"` becomes `"hi there"`.
+    pub fn content_without_emotes(&self) -> String {
+        let mut text = String::with_capacity(self.content.len());
+        let mut last = 0;
+        for emote in self.parse_emotes() {
+            text.push_str(&self.content[last..emote.range.start]);
+            text.push(' ');
+            last = emote.range.end;
+        }
+        text.push_str(&self.content[last..]);
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Parse the message as a bot command starting with `prefix`
+    ///
+    /// Surrounding whitespace is ignored and arguments are split on
+    /// whitespace, except inside double quotes; an unclosed quote runs to
+    /// the end of the message. Returns `None` if the message doesn't start
+    /// with `prefix` or no command name follows it.
+    ///
+    /// # Example
+    /// ```
+    /// # fn example(msg: kick_api::LiveChatMessage) {
+    /// // msg.content == "!so xqc \"great stream\""
+    /// if let Some(cmd) = msg.as_command('!') {
+    ///     match cmd.name.as_str() {
+    ///         "so" => println!("Shoutout to {:?}", cmd.args.first()),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn as_command(&self, prefix: char) -> Option<ChatCommand> {
+        let rest = self.content.trim().strip_prefix(prefix)?;
+        let (name, raw_args) = match rest.split_once(char::is_whitespace) {
+            Some((name, args)) => (name, args.trim_start()),
+            None => (rest, ""),
+        };
+        if name.is_empty() {
+            return None;
+        }
+
+        Some(ChatCommand {
+            name: name.to_string(),
+            args: split_args(raw_args),
+            raw_args: raw_args.to_string(),
+        })
+    }
+
+    /// Build a request replying to this message
+    ///
+    /// Pusher payloads only carry the chatroom ID, which is not the
+    /// broadcaster's user ID Kick's send endpoint needs, so pass that in.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # async fn example(client: kick_api::KickApiClient, msg: kick_api::LiveChatMessage) -> kick_api::Result<()> {
+    /// if msg.content == "!ping" {
+    ///     client.chat().send_message(msg.reply(12345, "pong")).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reply(&self, broadcaster_user_id: u64, content: &str) -> super::SendMessageRequest {
+        super::SendMessageRequest {
+            r#type: super::MessageType::User,
+            content: content.to_string(),
+            broadcaster_user_id: Some(broadcaster_user_id),
+            reply_to_message_id: Some(self.id.clone()),
+        }
+    }
+}
+
+/// A bot command parsed from a chat message by
+/// [`LiveChatMessage::as_command`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatCommand {
+    /// Command name without the prefix (e.g. `"so"` for `"!so xqc"`)
+    pub name: String,
+
+    /// Arguments split on whitespace, with quotes removed
+    pub args: Vec<String>,
+
+    /// Everything after the command name, as typed
+    pub raw_args: String,
+}
+
+/// Split command arguments on whitespace, keeping double-quoted runs
+/// together
+fn split_args(raw: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut quoted = false;
+
+    for c in raw.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_token = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_token {
+                    args.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_token = true;
+            }
+        }
+    }
+    if in_token {
+        args.push(current);
+    }
+    args
+}
+
+/// An emote embedded in chat message content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Emote {
+    /// Kick emote identifier
+    pub id: u64,
+
+    /// Emote name (e.g. `"KEKW"`)
+    pub name: String,
+
+    /// Byte range of the `[emote:id:name]` token in the message content
+    pub range: Range<usize>,
+}
+
+/// Find every well-formed `[emote:id:name]` token in `content`
+fn parse_emotes(content: &str) -> Vec<Emote> {
+    const PREFIX: &str = "[emote:";
+
+    let mut emotes = Vec::new();
+    let mut offset = 0;
+    while let Some(found) = content[offset..].find(PREFIX) {
+        let start = offset + found;
+        let body_start = start + PREFIX.len();
+        let Some(len) = content[body_start..].find(']') else {
+            break;
+        };
+        let end = body_start + len + 1;
+
+        let emote = content[body_start..end - 1]
+            .split_once(':')
+            .and_then(|(id, name)| {
+                let id = id.parse().ok()?;
+                let valid = !name.is_empty() && !name.contains(['[', ':']);
+                valid.then(|| Emote {
+                    id,
+                    name: name.to_string(),
+                    range: start..end,
+                })
+            });
+
+        match emote {
+            Some(emote) => {
+                emotes.push(emote);
+                offset = end;
+            }
+            // Not an emote; look for another token after this `[`
+            None => offset = start + 1,
+        }
+    }
+    emotes
+}
+
+/// Chatroom moderation settings, from a `ChatroomUpdatedEvent`
+///
+/// Kick broadcasts this event on the chatroom channel whenever a moderator
+/// changes the chat mode. See [`LiveChatClient::chat_settings`](crate::LiveChatClient::chat_settings).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSettings {
+    /// The chatroom these settings apply to
+    pub id: u64,
+
+    /// Slow mode: minimum seconds between messages per user
+    #[serde(default)]
+    pub slow_mode: SlowMode,
+
+    /// Subscribers-only mode
+    #[serde(default)]
+    pub subscribers_mode: ChatModeToggle,
+
+    /// Followers-only mode
+    #[serde(default)]
+    pub followers_mode: FollowersMode,
+
+    /// Emote-only mode
+    #[serde(default)]
+    pub emotes_mode: ChatModeToggle,
+}
+
+/// A chat mode that is simply on or off
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChatModeToggle {
+    /// Whether the mode is enabled
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// Slow mode state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SlowMode {
+    /// Whether slow mode is enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Seconds each user must wait between messages
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub message_interval: Option<u32>,
+}
+
+/// Followers-only mode state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FollowersMode {
+    /// Whether followers-only mode is enabled
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Minutes a user must have followed before chatting
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
+    pub min_duration: Option<u32>,
+}
+
 /// Metadata attached to a reply message
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessageMetadata {
     /// The original message being replied to
     #[serde(default)]
@@ -64,19 +626,19 @@ pub struct ChatMessageMetadata {
 }
 
 /// The sender of the message being replied to
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OriginalSender {
     pub username: String,
 }
 
 /// The content of the message being replied to
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OriginalMessage {
     pub content: String,
 }
 
 /// Sender information for a live chat message
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatSender {
     /// Unique user identifier
     pub id: u64,
@@ -92,18 +654,96 @@ pub struct ChatSender {
     pub identity: ChatIdentity,
 }
 
+impl ChatSender {
+    /// Number of months this user has been subscribed, read from their
+    /// subscriber badge
+    ///
+    /// Returns `None` if the user has no subscriber badge or the badge
+    /// carries no count.
+    pub fn subscriber_months(&self) -> Option<u32> {
+        self.identity
+            .badges
+            .iter()
+            .find(|b| b.kind() == BadgeKind::Subscriber)
+            .and_then(|b| b.count)
+    }
+
+    /// Whether the user has a badge of this kind
+    pub fn has_badge(&self, kind: BadgeKind) -> bool {
+        self.identity.badges.iter().any(|b| b.kind() == kind)
+    }
+
+    /// Whether the user owns the channel
+    pub fn is_broadcaster(&self) -> bool {
+        self.has_badge(BadgeKind::Broadcaster)
+    }
+
+    /// Whether the user moderates the channel
+    ///
+    /// Only checks the moderator badge; combine with
+    /// [`is_broadcaster`](Self::is_broadcaster) for "can moderate" checks.
+    pub fn is_moderator(&self) -> bool {
+        self.has_badge(BadgeKind::Moderator)
+    }
+
+    /// Whether the user is a VIP in the channel
+    pub fn is_vip(&self) -> bool {
+        self.has_badge(BadgeKind::Vip)
+    }
+
+    /// Whether the user has the OG badge
+    pub fn is_og(&self) -> bool {
+        self.has_badge(BadgeKind::Og)
+    }
+
+    /// Whether the user is subscribed to the channel
+    ///
+    /// Founders count as subscribers.
+    pub fn is_subscriber(&self) -> bool {
+        self.has_badge(BadgeKind::Subscriber) || self.has_badge(BadgeKind::Founder)
+    }
+
+    /// The most significant role this user holds in the chatroom
+    ///
+    /// Roles are ranked in the order of [`BadgeKind`]'s variants. Returns
+    /// [`BadgeKind::Viewer`] if the user has no recognized badge.
+    pub fn highest_role(&self) -> BadgeKind {
+        self.identity
+            .badges
+            .iter()
+            .map(ChatBadge::kind)
+            .filter(|k| !matches!(k, BadgeKind::Other(_)))
+            .min()
+            .unwrap_or(BadgeKind::Viewer)
+    }
+
+    /// The user's badges in Kick's display order
+    ///
+    /// Role badges come first, ranked in the order of [`BadgeKind`]'s
+    /// variants (broadcaster, moderator, VIP, ...). Badges of equal rank and
+    /// unrecognized badges keep their payload order, after the known ones.
+    /// Use [`ChatIdentity::badges`] directly for the raw payload order.
+    pub fn badges_ordered(&self) -> Vec<&ChatBadge> {
+        let mut badges: Vec<&ChatBadge> = self.identity.badges.iter().collect();
+        badges.sort_by_key(|b| b.kind().display_rank());
+        badges
+    }
+}
+
 /// Visual identity information for a chat sender
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatIdentity {
     /// Username color hex code
     pub color: String,
 
-    /// List of badges the user has
+    /// List of badges the user has, in the order Kick sent them
+    ///
+    /// See [`ChatSender::badges_ordered`] for Kick's display order.
     pub badges: Vec<ChatBadge>,
 }
 
 /// A badge displayed next to a user's name in chat
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatBadge {
     /// Badge type identifier
     #[serde(rename = "type")]
@@ -113,6 +753,374 @@ pub struct ChatBadge {
     pub text: String,
 
     /// Optional count (e.g. subscription months)
-    #[serde(default)]
+    #[serde(default, deserialize_with = "super::de::option_number_or_string")]
     pub count: Option<u32>,
 }
+
+impl ChatBadge {
+    /// Classify this badge by its `type` identifier
+    pub fn kind(&self) -> BadgeKind {
+        BadgeKind::from_type(&self.r#type)
+    }
+}
+
+/// Known Kick chat badge types
+///
+/// Variants are ordered from most to least significant role, so they can be
+/// compared to find a user's highest role.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BadgeKind {
+    /// Kick staff member
+    Staff,
+    /// The channel owner
+    Broadcaster,
+    /// Channel moderator
+    Moderator,
+    /// Channel VIP
+    Vip,
+    /// OG badge
+    Og,
+    /// One of the channel's founding subscribers
+    Founder,
+    /// Verified account
+    Verified,
+    /// Channel subscriber
+    Subscriber,
+    /// Has gifted subscriptions in the channel
+    SubGifter,
+    /// A badge type this crate doesn't recognize
+    Other(String),
+    /// No role badge at all (only returned by [`ChatSender::highest_role`])
+    Viewer,
+}
+
+impl BadgeKind {
+    /// Map a badge `type` identifier (e.g. `"moderator"`) to its kind
+    pub fn from_type(badge_type: &str) -> Self {
+        match badge_type {
+            "staff" => BadgeKind::Staff,
+            "broadcaster" => BadgeKind::Broadcaster,
+            "moderator" => BadgeKind::Moderator,
+            "vip" => BadgeKind::Vip,
+            "og" => BadgeKind::Og,
+            "founder" => BadgeKind::Founder,
+            "verified" => BadgeKind::Verified,
+            "subscriber" => BadgeKind::Subscriber,
+            "sub_gifter" => BadgeKind::SubGifter,
+            other => BadgeKind::Other(other.to_string()),
+        }
+    }
+
+    /// Position in display order, with all unrecognized badges ranked equal
+    fn display_rank(&self) -> u8 {
+        match self {
+            BadgeKind::Staff => 0,
+            BadgeKind::Broadcaster => 1,
+            BadgeKind::Moderator => 2,
+            BadgeKind::Vip => 3,
+            BadgeKind::Og => 4,
+            BadgeKind::Founder => 5,
+            BadgeKind::Verified => 6,
+            BadgeKind::Subscriber => 7,
+            BadgeKind::SubGifter => 8,
+            BadgeKind::Other(_) => 9,
+            BadgeKind::Viewer => 10,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_emotes() {
+        let content = "gg [emote:37226:KEKW][emote:39261:kkHuh] wow [emote:x:bad] [emote:1:ok]";
+        let emotes = parse_emotes(content);
+
+        let names: Vec<&str> = emotes.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["KEKW", "kkHuh", "ok"]);
+        assert_eq!(emotes[0].id, 37226);
+        assert_eq!(&content[emotes[1].range.clone()], "[emote:39261:kkHuh]");
+
+        let msg: LiveChatMessage = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "content": content,
+            "type": "message",
+            "sender": { "id": 1, "username": "u", "identity": { "color": "", "badges": [] } },
+        }))
+        .unwrap();
+        assert_eq!(msg.content_without_emotes(), "gg wow [emote:x:bad]");
+        assert!(parse_emotes("no emotes [emote:12").is_empty());
+    }
+
+    #[test]
+    fn test_as_command() {
+        let msg = |content: &str| -> LiveChatMessage {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "content": content,
+                "type": "message",
+                "sender": { "id": 1, "username": "u", "identity": { "color": "", "badges": [] } },
+            }))
+            .unwrap()
+        };
+
+        let cmd = msg("  !so  xqc \"great stream\" \"\" ")
+            .as_command('!')
+            .unwrap();
+        assert_eq!(cmd.name, "so");
+        assert_eq!(cmd.args, ["xqc", "great stream", ""]);
+        assert_eq!(cmd.raw_args, "xqc \"great stream\" \"\"");
+
+        let cmd = msg("!ping").as_command('!').unwrap();
+        assert_eq!((cmd.name.as_str(), cmd.args.len()), ("ping", 0));
+        assert_eq!(
+            msg("!say \"unclosed quote").as_command('!').unwrap().args,
+            ["unclosed quote"]
+        );
+
+        assert!(msg("hello !ping").as_command('!').is_none());
+        assert!(msg("! ping").as_command('!').is_none());
+        assert!(msg("!ping").as_command('?').is_none());
+    }
+
+    #[test]
+    fn test_chat_message_round_trip() {
+        let msg: LiveChatMessage = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "content": "hi",
+            "type": "message",
+            "sender": {
+                "id": 7,
+                "username": "u",
+                "slug": "u",
+                "identity": { "color": "#fff", "badges": [{ "type": "moderator", "text": "Moderator" }] },
+            },
+        }))
+        .unwrap();
+
+        let json = serde_json::to_string(&msg).unwrap();
+        let back: LiveChatMessage = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.sender.id, 7);
+        assert_eq!(back.sender.identity.badges.len(), 1);
+        assert_eq!(back.content, "hi");
+    }
+
+    #[test]
+    fn test_typed_chat_events() {
+        let event = |name: &str, data: serde_json::Value| PusherEvent {
+            event: name.to_string(),
+            channel: Some("chatrooms.42.v2".to_string()),
+            data: data.to_string(),
+        };
+
+        let banned = event(
+            UserBanned::EVENT,
+            serde_json::json!({
+                "id": "ban_1",
+                "user": { "id": 1, "username": "spammer", "slug": "spammer" },
+                "banned_by": { "id": 2, "username": "mod", "slug": "mod" },
+                "permanent": false,
+                "duration": 10,
+                "expires_at": "2025-01-14T16:18:06Z",
+            }),
+        );
+        match KickChatEvent::from(banned) {
+            KickChatEvent::UserBanned(ban) => {
+                assert_eq!(ban.user.username, "spammer");
+                assert_eq!(ban.duration, Some(10));
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        let deleted = event(
+            MessageDeleted::EVENT,
+            serde_json::json!({ "id": "del_1", "message": { "id": "msg_1" }, "aiModerated": true }),
+        );
+        match KickChatEvent::from(deleted) {
+            KickChatEvent::MessageDeleted(deleted) => {
+                assert_eq!(deleted.message_id(), "msg_1");
+                assert!(deleted.ai_moderated);
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        let message = event(
+            "App\\Events\\ChatMessageEvent",
+            serde_json::json!({
+                "id": "msg_2",
+                "content": "hi",
+                "type": "message",
+                "sender": { "id": 3, "username": "viewer", "identity": { "color": "", "badges": [] } },
+            }),
+        );
+        match KickChatEvent::from(message) {
+            KickChatEvent::Message(msg) => assert_eq!(msg.chatroom_id, Some(42)),
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        // Unparseable payloads of known events fall back to Unknown
+        let broken = event(UserBanned::EVENT, serde_json::json!({ "user": null }));
+        assert!(matches!(
+            KickChatEvent::from(broken),
+            KickChatEvent::Unknown { .. }
+        ));
+    }
+
+    fn sender_with_badges(badges: serde_json::Value) -> ChatSender {
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "username": "viewer",
+            "identity": { "color": "#FFFFFF", "badges": badges },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_borrowed_envelope_decodes_double_encoded_data() {
+        let inner = serde_json::json!({
+            "id": "abc",
+            "content": "hello",
+            "type": "message",
+            "sender": {
+                "id": 1,
+                "username": "viewer",
+                "identity": { "color": "#FFFFFF", "badges": [] },
+            },
+        });
+        let frame = serde_json::json!({
+            "event": "App\\Events\\ChatMessageEvent",
+            "data": inner.to_string(),
+            "channel": "chatrooms.1.v2",
+        })
+        .to_string();
+
+        let envelope: PusherMessageRef<'_> = serde_json::from_str(&frame).unwrap();
+        assert_eq!(envelope.event, "App\\Events\\ChatMessageEvent");
+
+        let data: String = serde_json::from_str(envelope.data.get()).unwrap();
+        let msg: LiveChatMessage = serde_json::from_str(&data).unwrap();
+        assert_eq!(msg.id, "abc");
+        assert_eq!(msg.content, "hello");
+    }
+
+    #[test]
+    fn test_chat_settings_from_chatroom_updated_event() {
+        let settings: ChatSettings = serde_json::from_value(serde_json::json!({
+            "id": 27670567,
+            "slow_mode": { "enabled": true, "message_interval": 10 },
+            "subscribers_mode": { "enabled": false },
+            "followers_mode": { "enabled": true, "min_duration": "15" },
+            "emotes_mode": { "enabled": false },
+            "advanced_bot_protection": { "enabled": false, "remaining_time": 0 },
+        }))
+        .unwrap();
+
+        assert_eq!(settings.id, 27670567);
+        assert!(settings.slow_mode.enabled);
+        assert_eq!(settings.slow_mode.message_interval, Some(10));
+        assert!(!settings.subscribers_mode.enabled);
+        assert_eq!(settings.followers_mode.min_duration, Some(15));
+        assert!(!settings.emotes_mode.enabled);
+    }
+
+    #[test]
+    fn test_gifted_subscriptions_event() {
+        let event = PusherEvent {
+            event: GiftedSubscriptions::EVENT.to_string(),
+            channel: Some("chatrooms.1.v2".to_string()),
+            data: serde_json::json!({
+                "chatroom_id": 1,
+                "gifted_usernames": ["alice", "bob"],
+                "gifter_username": "carol",
+                "gifter_total": 12,
+            })
+            .to_string(),
+        };
+
+        let gifts = event.gifted_subscriptions().unwrap();
+        assert_eq!(gifts.recipients().collect::<Vec<_>>(), ["alice", "bob"]);
+        assert_eq!(gifts.count(), 2);
+        assert_eq!(gifts.gifter(), Some("carol"));
+        assert_eq!(gifts.gifter_total, Some(12));
+
+        let anonymous: GiftedSubscriptions = serde_json::from_value(serde_json::json!({
+            "gifted_usernames": ["dave"],
+            "gifter_username": null,
+        }))
+        .unwrap();
+        assert!(anonymous.is_anonymous());
+    }
+
+    #[test]
+    fn test_chatroom_id_from_channel() {
+        let event = |channel: Option<&str>| PusherEvent {
+            event: "App\\Events\\ChatMessageEvent".to_string(),
+            channel: channel.map(String::from),
+            data: String::new(),
+        };
+
+        assert_eq!(
+            event(Some("chatrooms.27670567.v2")).chatroom_id(),
+            Some(27670567)
+        );
+        assert_eq!(event(Some("chatroom_42")).chatroom_id(), Some(42));
+        assert_eq!(event(Some("channel.1")).chatroom_id(), None);
+        assert_eq!(event(None).chatroom_id(), None);
+    }
+
+    #[test]
+    fn test_subscriber_months_and_highest_role() {
+        let sender = sender_with_badges(serde_json::json!([
+            { "type": "subscriber", "text": "Subscriber", "count": 14 },
+            { "type": "moderator", "text": "Moderator" },
+            { "type": "custom_event", "text": "Event" },
+        ]));
+
+        assert_eq!(sender.subscriber_months(), Some(14));
+        assert_eq!(sender.highest_role(), BadgeKind::Moderator);
+        assert!(sender.is_moderator() && sender.is_subscriber());
+        assert!(!sender.is_broadcaster() && !sender.is_vip() && !sender.is_og());
+
+        let plain = sender_with_badges(serde_json::json!([]));
+        assert_eq!(plain.subscriber_months(), None);
+        assert_eq!(plain.highest_role(), BadgeKind::Viewer);
+        assert!(!plain.is_moderator() && !plain.is_subscriber());
+    }
+
+    #[test]
+    fn test_badges_ordered_keeps_wire_order_for_ties() {
+        let sender = sender_with_badges(serde_json::json!([
+            { "type": "zeta_event", "text": "Zeta" },
+            { "type": "subscriber", "text": "Subscriber", "count": 3 },
+            { "type": "alpha_event", "text": "Alpha" },
+            { "type": "broadcaster", "text": "Broadcaster" },
+            { "type": "moderator", "text": "Moderator" },
+        ]));
+
+        let wire: Vec<&str> = sender
+            .identity
+            .badges
+            .iter()
+            .map(|b| b.r#type.as_str())
+            .collect();
+        assert_eq!(wire[0], "zeta_event");
+
+        let ordered: Vec<&str> = sender
+            .badges_ordered()
+            .into_iter()
+            .map(|b| b.r#type.as_str())
+            .collect();
+        assert_eq!(
+            ordered,
+            [
+                "broadcaster",
+                "moderator",
+                "subscriber",
+                "zeta_event",
+                "alpha_event"
+            ]
+        );
+    }
+}
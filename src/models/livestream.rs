@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use super::Category;
+
+/// A currently live stream
+///
+/// Returned when browsing live streams via the `/livestreams` endpoint
+///
+/// # Example Response
+/// ```json
+/// {
+///   "broadcaster_user_id": 123456,
+///   "channel_id": 654321,
+///   "slug": "xqc",
+///   "stream_title": "LIVE NOW",
+///   "language": "en",
+///   "viewer_count": 15000,
+///   "started_at": "2024-01-01T00:00:00Z"
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Livestream {
+    /// Unique broadcaster user identifier
+    pub broadcaster_user_id: u32,
+
+    /// Current stream category
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+
+    /// Channel identifier
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
+    pub channel_id: u32,
+
+    /// Custom tags set by the streamer
+    #[serde(default)]
+    pub custom_tags: Vec<String>,
+
+    /// Whether the stream is marked as mature content
+    #[serde(default)]
+    pub has_mature_content: bool,
+
+    /// Stream language code (e.g., "en")
+    #[serde(default)]
+    pub language: String,
+
+    /// Channel URL slug (unique username)
+    pub slug: String,
+
+    /// When the stream started (ISO 8601)
+    #[serde(default)]
+    pub started_at: String,
+
+    /// Current stream title
+    #[serde(default)]
+    pub stream_title: String,
+
+    /// Stream thumbnail URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+
+    /// Current viewer count
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
+    pub viewer_count: u32,
+}
+
+/// Sort order for [`LivestreamFilter`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LivestreamSort {
+    /// Most viewers first
+    ViewerCount,
+    /// Most recently started first
+    StartedAt,
+}
+
+impl LivestreamSort {
+    /// The sort value as sent to Kick (e.g. `"viewer_count"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LivestreamSort::ViewerCount => "viewer_count",
+            LivestreamSort::StartedAt => "started_at",
+        }
+    }
+}
+
+/// Server-side filter for [`LivestreamsApi::list`](crate::LivestreamsApi::list)
+///
+/// Every field is optional; the default filter lists live streams in Kick's
+/// default order.
+///
+/// # Example
+/// ```
+/// use kick_api::{LivestreamFilter, LivestreamSort};
+///
+/// // The 10 biggest English streams in a category
+/// let filter = LivestreamFilter {
+///     category_id: Some(15),
+///     language: Some("en".to_string()),
+///     sort: Some(LivestreamSort::ViewerCount),
+///     limit: Some(10),
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LivestreamFilter {
+    /// Only streams in this category
+    pub category_id: Option<u32>,
+
+    /// Only streams in this language (e.g. `"en"`)
+    pub language: Option<String>,
+
+    /// Sort order
+    pub sort: Option<LivestreamSort>,
+
+    /// Maximum number of streams to return (1-100)
+    pub limit: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_livestream_parses_api_payload() {
+        let stream: Livestream = serde_json::from_str(
+            r#"{"broadcaster_user_id":1,"channel_id":"2","slug":"xqc",
+                "category":{"id":15,"name":"Just Chatting"},
+                "has_mature_content":false,"language":"en",
+                "started_at":"2024-01-01T00:00:00Z","stream_title":"LIVE",
+                "thumbnail":null,"viewer_count":15000}"#,
+        )
+        .unwrap();
+        assert_eq!(stream.channel_id, 2);
+        assert_eq!(stream.category.unwrap().name, "Just Chatting");
+        assert_eq!(stream.viewer_count, 15000);
+        assert!(stream.custom_tags.is_empty());
+    }
+}
@@ -1,18 +1,30 @@
 mod channel;
 mod chat;
+pub(crate) mod de;
 mod event;
 pub(crate) mod live_chat;
+mod livestream;
 mod moderation;
+mod page;
 mod reward;
+#[cfg(feature = "chrono")]
+mod timestamps;
 mod user;
+mod webhook;
 
 pub use channel::*;
 pub use chat::*;
 pub use event::*;
 pub use live_chat::{
-    LiveChatMessage, ChatSender, ChatIdentity, ChatBadge, PusherEvent,
-    ChatMessageMetadata, OriginalSender, OriginalMessage,
+    BadgeKind, ChatBadge, ChatCommand, ChatIdentity, ChatMessageMetadata, ChatModeToggle,
+    ChatSender, ChatSettings, ChatSubscription, ChatUser, DeletedMessage, Emote, FollowersMode,
+    GiftedSubscriptions, KickChatEvent, LiveChatMessage, MessageDeleted, OriginalMessage,
+    OriginalSender, PinnedMessage, PusherEvent, SlowMode, UserBanned,
 };
+pub use livestream::*;
 pub use moderation::*;
+pub use page::Page;
+pub(crate) use page::PageResponse;
 pub use reward::*;
-pub use user::*;
\ No newline at end of file
+pub use user::*;
+pub use webhook::*;
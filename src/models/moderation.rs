@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+use crate::error::{KickApiError, Result};
+
+/// Longest timeout Kick accepts, in seconds (7 days)
+pub const MAX_TIMEOUT_SECS: u32 = 7 * 24 * 60 * 60;
+
 /// Request body for banning a user
 ///
 /// If `duration` is provided, this is a timeout (temporary ban).
@@ -42,6 +47,74 @@ pub struct BanRequest {
     pub duration: Option<u32>,
 }
 
+impl BanRequest {
+    /// Create a permanent ban
+    ///
+    /// # Example
+    /// ```
+    /// use kick_api::BanRequest;
+    ///
+    /// let ban = BanRequest::permanent(12345, 67890).reason("Spamming");
+    /// assert_eq!(ban.duration, None);
+    /// ```
+    pub fn permanent(broadcaster_user_id: u64, user_id: u64) -> Self {
+        Self {
+            broadcaster_user_id,
+            user_id,
+            reason: None,
+            duration: None,
+        }
+    }
+
+    /// Create a timeout (temporary ban) lasting `seconds`
+    ///
+    /// Returns [`KickApiError::InvalidInput`] unless `seconds` is between 1
+    /// and [`MAX_TIMEOUT_SECS`].
+    ///
+    /// # Example
+    /// ```
+    /// use kick_api::BanRequest;
+    ///
+    /// let timeout = BanRequest::timeout(12345, 67890, 600)?.reason("Cool off");
+    /// assert_eq!(timeout.duration, Some(600));
+    /// # Ok::<(), kick_api::KickApiError>(())
+    /// ```
+    pub fn timeout(broadcaster_user_id: u64, user_id: u64, seconds: u32) -> Result<Self> {
+        let request = Self {
+            broadcaster_user_id,
+            user_id,
+            reason: None,
+            duration: Some(seconds),
+        };
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Set the reason shown for the ban
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Whether this request is a permanent ban rather than a timeout
+    pub fn is_permanent(&self) -> bool {
+        self.duration.is_none()
+    }
+
+    /// Check that a timeout duration is within Kick's allowed range
+    pub fn validate(&self) -> Result<()> {
+        match self.duration {
+            Some(secs) if secs == 0 || secs > MAX_TIMEOUT_SECS => {
+                Err(KickApiError::InvalidInput(format!(
+                    "Timeout duration must be between 1 and {} seconds, got {}",
+                    MAX_TIMEOUT_SECS, secs
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Request body for unbanning a user
 ///
 /// # Example
@@ -61,3 +134,31 @@ pub struct UnbanRequest {
     /// The user to unban
     pub user_id: u64,
 }
+
+/// Outcome of [`ModerationApi::ban_many`](crate::ModerationApi::ban_many) or
+/// [`ModerationApi::unban_many`](crate::ModerationApi::unban_many)
+#[derive(Debug, Default)]
+pub struct BulkModerationResult {
+    /// Users the ban or unban was applied to, in request order
+    pub succeeded: Vec<u64>,
+
+    /// Users it failed for, in request order
+    pub failed: Vec<FailedModeration>,
+}
+
+impl BulkModerationResult {
+    /// Whether every request succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A ban or unban that failed within a bulk operation
+#[derive(Debug)]
+pub struct FailedModeration {
+    /// The user the request was for
+    pub user_id: u64,
+
+    /// Why it failed
+    pub error: KickApiError,
+}
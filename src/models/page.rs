@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// One page of a cursor-paginated list
+///
+/// Pass `next_cursor` back to the same endpoint to fetch the following page.
+/// It is `None` on the last page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+
+    /// Cursor for the next page, `None` when this is the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Whether more pages follow this one
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+/// Kick's list response envelope: `{"data": [...], "pagination": {...}}`
+#[derive(Deserialize)]
+pub(crate) struct PageResponse<T> {
+    data: Vec<T>,
+    #[serde(default)]
+    pagination: Option<Pagination>,
+}
+
+#[derive(Deserialize)]
+struct Pagination {
+    #[serde(default)]
+    next_cursor: Option<String>,
+}
+
+impl<T> From<PageResponse<T>> for Page<T> {
+    fn from(response: PageResponse<T>) -> Self {
+        Page {
+            items: response.data,
+            next_cursor: response
+                .pagination
+                .and_then(|p| p.next_cursor)
+                .filter(|cursor| !cursor.is_empty()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_from_envelope() {
+        let response: PageResponse<u32> =
+            serde_json::from_str(r#"{"data":[1,2],"pagination":{"next_cursor":"abc"}}"#).unwrap();
+        let page = Page::from(response);
+        assert_eq!(page.items, [1, 2]);
+        assert_eq!(page.next_cursor.as_deref(), Some("abc"));
+
+        for last in [
+            r#"{"data":[]}"#,
+            r#"{"data":[],"pagination":{"next_cursor":""}}"#,
+        ] {
+            let page = Page::from(serde_json::from_str::<PageResponse<u32>>(last).unwrap());
+            assert!(!page.has_next());
+        }
+    }
+}
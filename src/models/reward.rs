@@ -1,4 +1,12 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::KickApiError;
+
+/// Longest reward title Kick accepts, in characters
+const MAX_TITLE_LEN: usize = 50;
+
+/// Longest reward description Kick accepts, in characters
+const MAX_DESCRIPTION_LEN: usize = 200;
 
 /// Channel reward structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,6 +21,7 @@ pub struct ChannelReward {
     pub description: String,
 
     /// Cost in channel points (minimum 1)
+    #[serde(deserialize_with = "super::de::number_or_string")]
     pub cost: u32,
 
     /// Whether the reward is enabled
@@ -36,8 +45,63 @@ pub struct ChannelReward {
     pub background_color: String,
 }
 
+/// Client-side filter for [`RewardsApi::get_all_filtered`](crate::RewardsApi::get_all_filtered)
+///
+/// Kick's rewards endpoint always returns every reward on the channel,
+/// including disabled and paused ones. The default filter keeps all of them,
+/// matching `get_all`.
+///
+/// # Example
+/// ```
+/// use kick_api::RewardFilter;
+///
+/// // Only rewards viewers can currently redeem
+/// let filter = RewardFilter {
+///     include_disabled: false,
+///     include_paused: false,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RewardFilter {
+    /// Keep rewards with `is_enabled == false`
+    pub include_disabled: bool,
+
+    /// Keep rewards with `is_paused == true`
+    pub include_paused: bool,
+}
+
+impl Default for RewardFilter {
+    fn default() -> Self {
+        Self {
+            include_disabled: true,
+            include_paused: true,
+        }
+    }
+}
+
+impl RewardFilter {
+    /// Check whether a reward passes this filter
+    pub fn matches(&self, reward: &ChannelReward) -> bool {
+        (self.include_disabled || reward.is_enabled) && (self.include_paused || !reward.is_paused)
+    }
+}
+
 /// Request body for creating a new reward
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Build one with [`CreateRewardRequest::builder`], which checks Kick's field
+/// limits, or fill in the fields directly.
+///
+/// # Example
+/// ```
+/// use kick_api::CreateRewardRequest;
+///
+/// let request = CreateRewardRequest::builder("Song Request", 500)
+///     .description("Request a song!")
+///     .user_input_required(true)
+///     .build()?;
+/// # Ok::<(), kick_api::KickApiError>(())
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateRewardRequest {
     pub title: String,
     pub cost: u32,
@@ -61,7 +125,148 @@ pub struct CreateRewardRequest {
     pub background_color: Option<String>,
 }
 
+impl CreateRewardRequest {
+    /// Start building a request for a reward with a title and cost
+    pub fn builder(title: impl Into<String>, cost: u32) -> CreateRewardRequestBuilder {
+        CreateRewardRequestBuilder {
+            request: CreateRewardRequest {
+                title: title.into(),
+                cost,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Check the request against Kick's field limits
+    ///
+    /// The title must be 1-50 characters, the description at most 200
+    /// characters, the cost at least 1, and the background color a
+    /// `#RRGGBB` hex code.
+    pub fn validate(&self) -> crate::Result<()> {
+        validate_title(&self.title)?;
+        if let Some(description) = &self.description {
+            validate_description(description)?;
+        }
+        validate_cost(self.cost)?;
+        if let Some(color) = &self.background_color {
+            validate_color(color)?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`CreateRewardRequest`], created by
+/// [`CreateRewardRequest::builder`]
+#[derive(Debug, Clone)]
+pub struct CreateRewardRequestBuilder {
+    request: CreateRewardRequest,
+}
+
+impl CreateRewardRequestBuilder {
+    /// Set the description shown to viewers
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.request.description = Some(description.into());
+        self
+    }
+
+    /// Create the reward enabled or disabled
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.request.is_enabled = Some(enabled);
+        self
+    }
+
+    /// Create the reward with redemptions paused
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.request.is_paused = Some(paused);
+        self
+    }
+
+    /// Ask viewers for text input when redeeming
+    pub fn user_input_required(mut self, required: bool) -> Self {
+        self.request.is_user_input_required = Some(required);
+        self
+    }
+
+    /// Accept redemptions automatically instead of queueing them
+    pub fn skip_request_queue(mut self, skip: bool) -> Self {
+        self.request.should_redemptions_skip_request_queue = Some(skip);
+        self
+    }
+
+    /// Set the background color (hex color code)
+    pub fn background_color(mut self, color: impl Into<String>) -> Self {
+        self.request.background_color = Some(color.into());
+        self
+    }
+
+    /// Finish the request, checking it with [`CreateRewardRequest::validate`]
+    pub fn build(self) -> crate::Result<CreateRewardRequest> {
+        self.request.validate()?;
+        Ok(self.request)
+    }
+}
+
+fn validate_title(title: &str) -> crate::Result<()> {
+    let len = title.chars().count();
+    if len == 0 || len > MAX_TITLE_LEN {
+        return Err(KickApiError::InvalidInput(format!(
+            "Reward title must be 1-{} characters, got {}",
+            MAX_TITLE_LEN, len
+        )));
+    }
+    Ok(())
+}
+
+fn validate_description(description: &str) -> crate::Result<()> {
+    let len = description.chars().count();
+    if len > MAX_DESCRIPTION_LEN {
+        return Err(KickApiError::InvalidInput(format!(
+            "Reward description must be at most {} characters, got {}",
+            MAX_DESCRIPTION_LEN, len
+        )));
+    }
+    Ok(())
+}
+
+fn validate_cost(cost: u32) -> crate::Result<()> {
+    if cost == 0 {
+        return Err(KickApiError::InvalidInput(
+            "Reward cost must be at least 1".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_color(color: &str) -> crate::Result<()> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(KickApiError::InvalidInput(format!(
+            "Reward background color must be a #RRGGBB hex code, got '{}'",
+            color
+        )));
+    }
+    Ok(())
+}
+
 /// Request body for updating a reward
+///
+/// Kick applies reward updates with merge-patch semantics: only the fields
+/// present in the request body are changed, and everything else keeps its
+/// current value. Fields left as `None` are omitted from the serialized
+/// body entirely, so pausing a reward will not clear its description.
+///
+/// # Example
+/// ```
+/// use kick_api::UpdateRewardRequest;
+///
+/// // Only `is_paused` is sent; title, cost, description etc. are untouched
+/// let update = UpdateRewardRequest {
+///     is_paused: Some(true),
+///     ..Default::default()
+/// };
+/// ```
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct UpdateRewardRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -89,6 +294,28 @@ pub struct UpdateRewardRequest {
     pub background_color: Option<String>,
 }
 
+impl UpdateRewardRequest {
+    /// Check the fields that are set against Kick's field limits
+    ///
+    /// Applies the same rules as [`CreateRewardRequest::validate`]; fields
+    /// left as `None` are not checked.
+    pub fn validate(&self) -> crate::Result<()> {
+        if let Some(title) = &self.title {
+            validate_title(title)?;
+        }
+        if let Some(description) = &self.description {
+            validate_description(description)?;
+        }
+        if let Some(cost) = self.cost {
+            validate_cost(cost)?;
+        }
+        if let Some(color) = &self.background_color {
+            validate_color(color)?;
+        }
+        Ok(())
+    }
+}
+
 /// Channel reward redemption
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChannelRewardRedemption {
@@ -116,12 +343,47 @@ pub struct RedemptionUser {
 }
 
 /// Redemption status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+///
+/// Statuses this crate doesn't know about are kept as `Unknown` instead of
+/// failing the whole response, so new states added by Kick don't break
+/// redemption parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum RedemptionStatus {
     Pending,
     Accepted,
     Rejected,
+    /// A status not recognized by this crate, holding the raw value
+    Unknown(String),
+}
+
+impl RedemptionStatus {
+    /// The status as sent by Kick (e.g. `"pending"`)
+    pub fn as_str(&self) -> &str {
+        match self {
+            RedemptionStatus::Pending => "pending",
+            RedemptionStatus::Accepted => "accepted",
+            RedemptionStatus::Rejected => "rejected",
+            RedemptionStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl Serialize for RedemptionStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RedemptionStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "pending" => RedemptionStatus::Pending,
+            "accepted" => RedemptionStatus::Accepted,
+            "rejected" => RedemptionStatus::Rejected,
+            _ => RedemptionStatus::Unknown(s),
+        })
+    }
 }
 
 /// Failed redemption (when batch operations fail)
@@ -152,7 +414,7 @@ pub struct ManageRedemptionsRequest {
 }
 
 /// Response when accepting/rejecting redemptions
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManageRedemptionsResponse {
     /// Successfully processed redemptions
     pub data: Vec<ChannelRewardRedemption>,
@@ -170,3 +432,94 @@ fn default_true() -> bool {
 fn default_color() -> String {
     "#00e701".to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_request_omits_unset_fields() {
+        let update = UpdateRewardRequest {
+            is_paused: Some(true),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(json, serde_json::json!({ "is_paused": true }));
+    }
+
+    #[test]
+    fn test_create_request_builder_validates() {
+        let request = CreateRewardRequest::builder("Hydrate", 100)
+            .description("Drink some water")
+            .skip_request_queue(true)
+            .build()
+            .unwrap();
+        assert_eq!(request.title, "Hydrate");
+        assert_eq!(request.should_redemptions_skip_request_queue, Some(true));
+        assert_eq!(request.is_enabled, None);
+
+        assert!(CreateRewardRequest::builder("", 100).build().is_err());
+        assert!(
+            CreateRewardRequest::builder("x".repeat(51), 100)
+                .build()
+                .is_err()
+        );
+        assert!(CreateRewardRequest::builder("Hydrate", 0).build().is_err());
+        assert!(
+            CreateRewardRequest::builder("Hydrate", 100)
+                .description("x".repeat(201))
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_reward_color_validation() {
+        for color in ["#00e701", "#FFAA00"] {
+            assert!(validate_color(color).is_ok(), "{}", color);
+        }
+        for color in ["00e701", "#00e70", "#00e7011", "#00g701", "#ＡＢＣ"] {
+            assert!(validate_color(color).is_err(), "{}", color);
+        }
+
+        let update = UpdateRewardRequest {
+            background_color: Some("green".to_string()),
+            ..Default::default()
+        };
+        assert!(update.validate().is_err());
+        assert!(UpdateRewardRequest::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_redemption_status_unknown_values() {
+        let statuses: Vec<RedemptionStatus> =
+            serde_json::from_str(r#"["pending","accepted","rejected","canceled"]"#).unwrap();
+        assert_eq!(
+            statuses,
+            vec![
+                RedemptionStatus::Pending,
+                RedemptionStatus::Accepted,
+                RedemptionStatus::Rejected,
+                RedemptionStatus::Unknown("canceled".to_string()),
+            ]
+        );
+
+        let json = serde_json::to_string(&statuses).unwrap();
+        assert_eq!(json, r#"["pending","accepted","rejected","canceled"]"#);
+    }
+
+    #[test]
+    fn test_reward_cost_accepts_number_or_string() {
+        let as_number = r#"{"id":"01H","title":"Hydrate","description":"","cost":500}"#;
+        let as_string = r#"{"id":"01H","title":"Hydrate","description":"","cost":"500"}"#;
+
+        let reward: ChannelReward = serde_json::from_str(as_number).unwrap();
+        assert_eq!(reward.cost, 500);
+        let reward: ChannelReward = serde_json::from_str(as_string).unwrap();
+        assert_eq!(reward.cost, 500);
+
+        let invalid = r#"{"id":"01H","title":"Hydrate","description":"","cost":"lots"}"#;
+        assert!(serde_json::from_str::<ChannelReward>(invalid).is_err());
+    }
+}
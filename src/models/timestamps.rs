@@ -0,0 +1,80 @@
+//! Typed timestamp accessors, enabled by the `chrono` feature
+//!
+//! Each accessor parses the raw ISO 8601 string field of the same name and
+//! returns `None` if it is missing or malformed.
+
+use chrono::{DateTime, Utc};
+
+use super::{ChannelRewardRedemption, EventSubscription, LiveChatMessage, Stream};
+use crate::time::parse_utc;
+
+impl ChannelRewardRedemption {
+    /// When the reward was redeemed
+    pub fn redeemed_at(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.redeemed_at)
+    }
+}
+
+impl Stream {
+    /// When the stream started
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.start_time)
+    }
+}
+
+impl EventSubscription {
+    /// When the subscription was created
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.created_at)
+    }
+
+    /// When the subscription was last updated
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.updated_at)
+    }
+}
+
+impl LiveChatMessage {
+    /// When the message was sent
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        parse_utc(self.created_at.as_deref()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_start_time() {
+        let stream: Stream =
+            serde_json::from_str(r#"{"is_live":true,"start_time":"2024-01-01T02:00:00+02:00"}"#)
+                .unwrap();
+        assert_eq!(
+            stream.start_time().unwrap().to_rfc3339(),
+            "2024-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_malformed_timestamps_are_none() {
+        for raw in [
+            "yesterday",
+            "2024-01-01T00:00:0é",
+            "2024-13-01T00:00:00Z",
+            "",
+        ] {
+            let stream: Stream =
+                serde_json::from_value(serde_json::json!({ "is_live": true, "start_time": raw }))
+                    .unwrap();
+            assert_eq!(stream.start_time(), None, "{raw:?}");
+        }
+
+        let stream: Stream =
+            serde_json::from_str(r#"{"is_live":true,"start_time":"2024-01-01 00:00:00"}"#).unwrap();
+        assert_eq!(
+            stream.start_time().unwrap().to_rfc3339(),
+            "2024-01-01T00:00:00+00:00"
+        );
+    }
+}
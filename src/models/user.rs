@@ -96,6 +96,21 @@ impl TokenIntrospection {
         };
         duration.as_secs() as i64 >= exp
     }
+
+    /// Get how much longer the token stays valid
+    ///
+    /// Returns `None` if the token has no expiry, and `Some(Duration::ZERO)`
+    /// once it has expired.
+    pub fn remaining_lifetime(&self) -> Option<std::time::Duration> {
+        let exp = self.exp?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs() as i64;
+        Some(std::time::Duration::from_secs(
+            exp.saturating_sub(now).max(0) as u64,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -140,4 +155,22 @@ mod tests {
 
         assert!(!valid.is_expired());
     }
+
+    #[test]
+    fn test_token_remaining_lifetime() {
+        let mut token = TokenIntrospection {
+            active: true,
+            client_id: None,
+            token_type: None,
+            scope: None,
+            exp: Some(0),
+        };
+        assert_eq!(token.remaining_lifetime(), Some(std::time::Duration::ZERO));
+
+        token.exp = Some(9999999999);
+        assert!(token.remaining_lifetime().unwrap() > std::time::Duration::from_secs(3600));
+
+        token.exp = None;
+        assert_eq!(token.remaining_lifetime(), None);
+    }
 }
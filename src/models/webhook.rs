@@ -0,0 +1,670 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{
+    Category, ChannelRewardRedemption, ChatBadge, ChatIdentity, ChatMessageMetadata, ChatSender,
+    KickEvent, LiveChatMessage, OriginalMessage, OriginalSender, RedemptionStatus, RedemptionUser,
+};
+use crate::error::Result;
+
+/// A user as described in webhook payloads
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookUser {
+    /// Unique user identifier
+    pub user_id: u64,
+
+    /// Username
+    pub username: String,
+
+    /// Whether the user is verified
+    #[serde(default)]
+    pub is_verified: bool,
+
+    /// Profile picture URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_picture: Option<String>,
+
+    /// The user's channel slug
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_slug: Option<String>,
+
+    /// Chat identity (username color and badges), on chat message senders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<WebhookIdentity>,
+}
+
+/// Chat identity of a user in webhook payloads
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookIdentity {
+    /// Username color hex code
+    #[serde(default)]
+    pub username_color: String,
+
+    /// Badges shown next to the username, in the order Kick sent them
+    #[serde(default)]
+    pub badges: Vec<WebhookBadge>,
+}
+
+/// A chat badge in webhook payloads
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookBadge {
+    /// Badge display text
+    pub text: String,
+
+    /// Badge type identifier
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    /// Optional count (e.g. subscription months)
+    #[serde(
+        default,
+        deserialize_with = "super::de::option_number_or_string",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub count: Option<u32>,
+}
+
+/// The message a chat message webhook replies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookReply {
+    /// Unique identifier of the original message
+    pub message_id: String,
+
+    /// Original message content
+    pub content: String,
+
+    /// Sender of the original message
+    pub sender: WebhookUser,
+}
+
+/// Payload of the `chat.message.sent` webhook
+///
+/// Use [`LiveChatMessage::from_webhook`] to handle webhook and live chat
+/// messages with the same code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessageSent {
+    /// Unique message identifier
+    pub message_id: String,
+
+    /// The message this one replies to, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replies_to: Option<WebhookReply>,
+
+    /// The channel the message was sent in
+    pub broadcaster: WebhookUser,
+
+    /// The user who sent the message
+    pub sender: WebhookUser,
+
+    /// Message text content
+    pub content: String,
+
+    /// When the message was sent (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+impl From<ChatMessageSent> for LiveChatMessage {
+    fn from(event: ChatMessageSent) -> Self {
+        let identity = event.sender.identity.unwrap_or(WebhookIdentity {
+            username_color: String::new(),
+            badges: Vec::new(),
+        });
+
+        LiveChatMessage {
+            id: event.message_id,
+            chatroom_id: None,
+            content: event.content,
+            r#type: if event.replies_to.is_some() {
+                "reply"
+            } else {
+                "message"
+            }
+            .to_string(),
+            created_at: event.created_at,
+            sender: ChatSender {
+                id: event.sender.user_id,
+                username: event.sender.username,
+                slug: event.sender.channel_slug,
+                identity: ChatIdentity {
+                    color: identity.username_color,
+                    badges: identity
+                        .badges
+                        .into_iter()
+                        .map(|b| ChatBadge {
+                            r#type: b.r#type,
+                            text: b.text,
+                            count: b.count,
+                        })
+                        .collect(),
+                },
+            },
+            metadata: event.replies_to.map(|reply| ChatMessageMetadata {
+                original_sender: Some(OriginalSender {
+                    username: reply.sender.username,
+                }),
+                original_message: Some(OriginalMessage {
+                    content: reply.content,
+                }),
+            }),
+        }
+    }
+}
+
+impl LiveChatMessage {
+    /// Parse a `chat.message.sent` webhook body into a live chat message
+    ///
+    /// Lets one handler process chat from both webhooks and
+    /// [`LiveChatClient`](crate::LiveChatClient). Webhooks don't carry the
+    /// chatroom ID, so `chatroom_id` is `None`; use the broadcaster from
+    /// [`ChatMessageSent`] if you need to tell channels apart.
+    ///
+    /// # Example
+    /// ```
+    /// use kick_api::LiveChatMessage;
+    ///
+    /// # fn handle(msg: &LiveChatMessage) {}
+    /// # fn example(webhook_body: &str) -> kick_api::Result<()> {
+    /// let msg = LiveChatMessage::from_webhook(webhook_body)?;
+    /// handle(&msg);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_webhook(body: &str) -> Result<Self> {
+        let event: ChatMessageSent = serde_json::from_str(body)?;
+        Ok(event.into())
+    }
+}
+
+/// The reward included in a redemption webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedeemedReward {
+    /// Unique reward identifier (ULID)
+    pub id: String,
+
+    /// Reward title
+    pub title: String,
+
+    /// Cost in channel points
+    #[serde(deserialize_with = "super::de::number_or_string")]
+    pub cost: u32,
+
+    /// Reward description
+    #[serde(default)]
+    pub description: String,
+}
+
+/// Payload of the `channel.reward.redemption.updated` webhook
+///
+/// Kick sends this webhook when a viewer redeems a reward and again whenever
+/// the redemption is accepted or rejected, so subscribing to
+/// [`KickEvent::ChannelRewardRedemptionUpdated`](super::KickEvent::ChannelRewardRedemptionUpdated)
+/// gives a real-time redemption feed without polling
+/// `RewardsApi::get_redemptions`. Kick does not publish redemptions on the
+/// Pusher chatroom channel, so [`LiveChatClient`](crate::LiveChatClient)
+/// can't see them.
+///
+/// # Example
+/// ```
+/// use kick_api::{RedemptionStatus, RewardRedemptionUpdated};
+///
+/// # fn example(webhook_body: &str) -> kick_api::Result<()> {
+/// let event: RewardRedemptionUpdated = serde_json::from_str(webhook_body)?;
+/// if event.status == RedemptionStatus::Pending {
+///     println!("{} redeemed {}", event.redeemer.username, event.reward.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RewardRedemptionUpdated {
+    /// Unique redemption identifier (ULID)
+    pub id: String,
+
+    /// User-provided input (if the reward requires it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_input: Option<String>,
+
+    /// Redemption status
+    pub status: RedemptionStatus,
+
+    /// When the reward was redeemed (ISO 8601)
+    pub redeemed_at: String,
+
+    /// The reward that was redeemed
+    pub reward: RedeemedReward,
+
+    /// The viewer who redeemed the reward
+    pub redeemer: WebhookUser,
+
+    /// The channel the reward belongs to
+    pub broadcaster: WebhookUser,
+}
+
+impl RewardRedemptionUpdated {
+    /// Convert into the redemption model returned by the REST API
+    pub fn to_redemption(&self) -> ChannelRewardRedemption {
+        ChannelRewardRedemption {
+            id: self.id.clone(),
+            redeemed_at: self.redeemed_at.clone(),
+            redeemer: RedemptionUser {
+                user_id: self.redeemer.user_id,
+            },
+            status: self.status.clone(),
+            user_input: self.user_input.clone(),
+        }
+    }
+}
+
+/// Payload of the `channel.followed` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelFollowed {
+    /// The channel that was followed
+    pub broadcaster: WebhookUser,
+
+    /// The new follower
+    pub follower: WebhookUser,
+}
+
+/// Payload of the `channel.subscription.new` and
+/// `channel.subscription.renewal` webhooks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSubscription {
+    /// The channel subscribed to
+    pub broadcaster: WebhookUser,
+
+    /// The subscriber
+    pub subscriber: WebhookUser,
+
+    /// Total months subscribed
+    #[serde(default, deserialize_with = "super::de::number_or_string")]
+    pub duration: u32,
+
+    /// When the subscription started or renewed (ISO 8601)
+    #[serde(default)]
+    pub created_at: String,
+
+    /// When the subscription expires (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+/// Payload of the `channel.subscription.gifts` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSubscriptionGifts {
+    /// The channel the subscriptions were gifted in
+    pub broadcaster: WebhookUser,
+
+    /// The gifter, or `None` for an anonymous gift
+    #[serde(default, deserialize_with = "known_user")]
+    pub gifter: Option<WebhookUser>,
+
+    /// The users who received a subscription
+    #[serde(default)]
+    pub giftees: Vec<WebhookUser>,
+
+    /// When the subscriptions were gifted (ISO 8601)
+    #[serde(default)]
+    pub created_at: String,
+
+    /// When the gifted subscriptions expire (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+/// Payload of the `livestream.status.updated` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivestreamStatusUpdated {
+    /// The channel whose stream changed
+    pub broadcaster: WebhookUser,
+
+    /// Whether the stream is now live
+    pub is_live: bool,
+
+    /// Stream title
+    #[serde(default)]
+    pub title: String,
+
+    /// When the stream started (ISO 8601)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+
+    /// When the stream ended (ISO 8601), if it went offline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<String>,
+}
+
+/// Payload of the `livestream.metadata.updated` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivestreamMetadataUpdated {
+    /// The channel whose stream changed
+    pub broadcaster: WebhookUser,
+
+    /// The new stream metadata
+    pub metadata: LivestreamMetadata,
+}
+
+/// Stream metadata in the `livestream.metadata.updated` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LivestreamMetadata {
+    /// Stream title
+    #[serde(default)]
+    pub title: String,
+
+    /// Stream language code (e.g., "en")
+    #[serde(default)]
+    pub language: String,
+
+    /// Whether the stream is marked as mature content
+    #[serde(default)]
+    pub has_mature_content: bool,
+
+    /// Stream category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+}
+
+/// Payload of the `moderation.banned` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationBanned {
+    /// The channel the user was banned from
+    pub broadcaster: WebhookUser,
+
+    /// The moderator who issued the ban
+    pub moderator: WebhookUser,
+
+    /// The banned user
+    pub banned_user: WebhookUser,
+
+    /// Ban details
+    pub metadata: BanMetadata,
+}
+
+/// Ban details in the `moderation.banned` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BanMetadata {
+    /// Reason given for the ban
+    #[serde(default)]
+    pub reason: String,
+
+    /// When the ban was issued (ISO 8601)
+    #[serde(default)]
+    pub created_at: String,
+
+    /// When a timeout ends (ISO 8601), or `None` for a permanent ban
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+/// Payload of the `kicks.gifted` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KicksGifted {
+    /// The channel the KICKs were gifted to
+    pub broadcaster: WebhookUser,
+
+    /// The user who gifted the KICKs
+    pub sender: WebhookUser,
+
+    /// The gift
+    pub gift: KicksGift,
+
+    /// When the KICKs were gifted (ISO 8601)
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// The gift in a `kicks.gifted` webhook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KicksGift {
+    /// Number of KICKs gifted
+    #[serde(deserialize_with = "super::de::number_or_string")]
+    pub amount: u32,
+
+    /// Gift name
+    #[serde(default)]
+    pub name: String,
+
+    /// Gift type identifier
+    #[serde(default, rename = "type")]
+    pub r#type: String,
+
+    /// Gift tier
+    #[serde(default)]
+    pub tier: String,
+
+    /// Message attached to the gift
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// A parsed webhook delivery
+///
+/// Built by [`parse_webhook`](crate::parse_webhook) from the
+/// `Kick-Event-Type` header and the raw body. Each variant matches a
+/// [`KickEvent`]; event types this crate doesn't know yet come back as
+/// [`Unknown`](Self::Unknown) so new Kick events don't break a handler.
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    /// `chat.message.sent`
+    ChatMessageSent(ChatMessageSent),
+    /// `channel.followed`
+    ChannelFollowed(ChannelFollowed),
+    /// `channel.subscription.renewal`
+    ChannelSubscriptionRenewal(ChannelSubscription),
+    /// `channel.subscription.gifts`
+    ChannelSubscriptionGifts(ChannelSubscriptionGifts),
+    /// `channel.subscription.new`
+    ChannelSubscriptionNew(ChannelSubscription),
+    /// `channel.reward.redemption.updated`
+    ChannelRewardRedemptionUpdated(RewardRedemptionUpdated),
+    /// `livestream.status.updated`
+    LivestreamStatusUpdated(LivestreamStatusUpdated),
+    /// `livestream.metadata.updated`
+    LivestreamMetadataUpdated(LivestreamMetadataUpdated),
+    /// `moderation.banned`
+    ModerationBanned(ModerationBanned),
+    /// `kicks.gifted`
+    KicksGifted(KicksGifted),
+    /// An event type this crate doesn't recognize
+    Unknown {
+        /// The `Kick-Event-Type` header value
+        event_type: String,
+        /// The parsed JSON body
+        body: serde_json::Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Parse a webhook body according to its `Kick-Event-Type`
+    pub fn parse(event_type: &str, body: &[u8]) -> Result<Self> {
+        let Some(kind) = KickEvent::from_name(event_type) else {
+            return Ok(WebhookEvent::Unknown {
+                event_type: event_type.to_string(),
+                body: serde_json::from_slice(body)?,
+            });
+        };
+
+        Ok(match kind {
+            KickEvent::ChatMessageSent => {
+                WebhookEvent::ChatMessageSent(serde_json::from_slice(body)?)
+            }
+            KickEvent::ChannelFollowed => {
+                WebhookEvent::ChannelFollowed(serde_json::from_slice(body)?)
+            }
+            KickEvent::ChannelSubscriptionRenewal => {
+                WebhookEvent::ChannelSubscriptionRenewal(serde_json::from_slice(body)?)
+            }
+            KickEvent::ChannelSubscriptionGifts => {
+                WebhookEvent::ChannelSubscriptionGifts(serde_json::from_slice(body)?)
+            }
+            KickEvent::ChannelSubscriptionNew => {
+                WebhookEvent::ChannelSubscriptionNew(serde_json::from_slice(body)?)
+            }
+            KickEvent::ChannelRewardRedemptionUpdated => {
+                WebhookEvent::ChannelRewardRedemptionUpdated(serde_json::from_slice(body)?)
+            }
+            KickEvent::LivestreamStatusUpdated => {
+                WebhookEvent::LivestreamStatusUpdated(serde_json::from_slice(body)?)
+            }
+            KickEvent::LivestreamMetadataUpdated => {
+                WebhookEvent::LivestreamMetadataUpdated(serde_json::from_slice(body)?)
+            }
+            KickEvent::ModerationBanned => {
+                WebhookEvent::ModerationBanned(serde_json::from_slice(body)?)
+            }
+            KickEvent::KicksGifted => WebhookEvent::KicksGifted(serde_json::from_slice(body)?),
+        })
+    }
+
+    /// The event type, or `None` for [`Unknown`](Self::Unknown) events
+    pub fn kind(&self) -> Option<KickEvent> {
+        Some(match self {
+            WebhookEvent::ChatMessageSent(_) => KickEvent::ChatMessageSent,
+            WebhookEvent::ChannelFollowed(_) => KickEvent::ChannelFollowed,
+            WebhookEvent::ChannelSubscriptionRenewal(_) => KickEvent::ChannelSubscriptionRenewal,
+            WebhookEvent::ChannelSubscriptionGifts(_) => KickEvent::ChannelSubscriptionGifts,
+            WebhookEvent::ChannelSubscriptionNew(_) => KickEvent::ChannelSubscriptionNew,
+            WebhookEvent::ChannelRewardRedemptionUpdated(_) => {
+                KickEvent::ChannelRewardRedemptionUpdated
+            }
+            WebhookEvent::LivestreamStatusUpdated(_) => KickEvent::LivestreamStatusUpdated,
+            WebhookEvent::LivestreamMetadataUpdated(_) => KickEvent::LivestreamMetadataUpdated,
+            WebhookEvent::ModerationBanned(_) => KickEvent::ModerationBanned,
+            WebhookEvent::KicksGifted(_) => KickEvent::KicksGifted,
+            WebhookEvent::Unknown { .. } => return None,
+        })
+    }
+}
+
+/// Deserialize a user that Kick sends with null fields when anonymous
+fn known_user<'de, D>(deserializer: D) -> std::result::Result<Option<WebhookUser>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|v| serde_json::from_value(v).ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::BadgeKind;
+
+    #[test]
+    fn test_chat_message_webhook_into_live_chat_message() {
+        let body = serde_json::json!({
+            "message_id": "unique_message_id_123",
+            "replies_to": {
+                "message_id": "original_456",
+                "content": "first!",
+                "sender": { "is_anonymous": false, "user_id": 7, "username": "early_bird" },
+            },
+            "broadcaster": {
+                "is_anonymous": false,
+                "user_id": 123456789,
+                "username": "broadcaster_name",
+                "is_verified": true,
+                "profile_picture": "https://example.com/broadcaster_avatar.jpg",
+                "channel_slug": "broadcaster_channel",
+                "identity": null,
+            },
+            "sender": {
+                "is_anonymous": false,
+                "user_id": 987654321,
+                "username": "sender_name",
+                "is_verified": false,
+                "profile_picture": "https://example.com/sender_avatar.jpg",
+                "channel_slug": "sender_channel",
+                "identity": {
+                    "username_color": "#FF5733",
+                    "badges": [
+                        { "text": "Moderator", "type": "moderator" },
+                        { "text": "Sub Gifter", "type": "sub_gifter", "count": 5 },
+                    ],
+                },
+            },
+            "content": "Hello [emote:12345:Kappa]",
+            "emotes": [],
+            "created_at": "2025-01-14T16:08:06Z",
+        })
+        .to_string();
+
+        let msg = LiveChatMessage::from_webhook(&body).unwrap();
+        assert_eq!(msg.id, "unique_message_id_123");
+        assert_eq!(msg.r#type, "reply");
+        assert_eq!(msg.sender.id, 987654321);
+        assert_eq!(msg.sender.slug.as_deref(), Some("sender_channel"));
+        assert_eq!(msg.sender.identity.color, "#FF5733");
+        assert_eq!(msg.sender.highest_role(), BadgeKind::Moderator);
+        let metadata = msg.metadata.unwrap();
+        assert_eq!(metadata.original_sender.unwrap().username, "early_bird");
+        assert_eq!(metadata.original_message.unwrap().content, "first!");
+    }
+
+    #[test]
+    fn test_redemption_webhook_payload() {
+        let event: RewardRedemptionUpdated = serde_json::from_value(serde_json::json!({
+            "id": "01KBHE78QE4HZY1617DK5FC7YD",
+            "user_input": "play despacito",
+            "status": "pending",
+            "redeemed_at": "2025-12-02T22:54:19.323Z",
+            "reward": {
+                "id": "01KBHE6ZNBP44RH2ZSMFWJ08E8",
+                "title": "Song request",
+                "cost": 500,
+                "description": "Pick the next song",
+            },
+            "redeemer": {
+                "user_id": 123,
+                "username": "viewer",
+                "is_verified": false,
+                "profile_picture": "",
+                "channel_slug": "viewer",
+            },
+            "broadcaster": {
+                "user_id": 456,
+                "username": "streamer",
+                "is_verified": true,
+                "profile_picture": "",
+                "channel_slug": "streamer",
+            },
+        }))
+        .unwrap();
+
+        assert_eq!(event.reward.cost, 500);
+        let redemption = event.to_redemption();
+        assert_eq!(redemption.id, "01KBHE78QE4HZY1617DK5FC7YD");
+        assert_eq!(redemption.redeemer.user_id, 123);
+        assert_eq!(redemption.status, RedemptionStatus::Pending);
+        assert_eq!(redemption.user_input.as_deref(), Some("play despacito"));
+    }
+
+    #[test]
+    fn test_parse_webhook_events() {
+        let gifts = serde_json::json!({
+            "broadcaster": { "user_id": 1, "username": "streamer" },
+            "gifter": { "is_anonymous": true, "user_id": null, "username": null },
+            "giftees": [
+                { "user_id": 2, "username": "lucky" },
+                { "user_id": 3, "username": "luckier" },
+            ],
+            "created_at": "2025-01-14T16:08:06Z",
+            "expires_at": "2025-02-14T16:08:06Z",
+        })
+        .to_string();
+        match WebhookEvent::parse("channel.subscription.gifts", gifts.as_bytes()).unwrap() {
+            WebhookEvent::ChannelSubscriptionGifts(event) => {
+                assert!(event.gifter.is_none());
+                assert_eq!(event.giftees.len(), 2);
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+
+        let unknown = WebhookEvent::parse("channel.raided", br#"{"viewers":5}"#).unwrap();
+        assert!(unknown.kind().is_none());
+        assert!(
+            matches!(unknown, WebhookEvent::Unknown { ref event_type, .. } if event_type == "channel.raided")
+        );
+
+        assert!(WebhookEvent::parse("channel.followed", b"{}").is_err());
+    }
+}
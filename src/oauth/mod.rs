@@ -1,15 +1,19 @@
 use oauth2::{
-    AuthUrl, ClientId, ClientSecret, CsrfToken,
-    PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenUrl,
-    basic::BasicClient,
+    AuthUrl, ClientId, ClientSecret, CsrfToken, PkceCodeChallenge, PkceCodeVerifier, RedirectUrl,
+    Scope, TokenUrl, basic::BasicClient,
 };
-use serde::Deserialize;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
 use std::env;
+use std::time::{Duration, SystemTime};
+
+use crate::error::KickApiError;
 
 /// OAuth token response from Kick
 ///
-/// Returned by `exchange_code()` and `refresh_token()`.
-#[derive(Debug, Clone, Deserialize)]
+/// Returned by `exchange_code()`, `refresh_token()` and
+/// `get_app_access_token()`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OAuthTokenResponse {
     /// The access token for API requests
     pub access_token: String,
@@ -21,15 +25,72 @@ pub struct OAuthTokenResponse {
     pub expires_in: u64,
 
     /// Space-separated list of granted scopes
+    ///
+    /// Empty when Kick omits it, as it may for app access tokens.
+    #[serde(default)]
     pub scope: String,
 
     /// Token type (typically "Bearer")
     pub token_type: String,
+
+    /// When the access token expires
+    ///
+    /// Not part of Kick's response; filled in from `expires_in` when the
+    /// response is received by the token methods on [`KickOAuth`]. Kept when
+    /// the response is serialized, so a persisted token still knows when it
+    /// expires after being loaded again.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<SystemTime>,
+}
+
+impl OAuthTokenResponse {
+    /// Parse a token endpoint response body, stamping `expires_at`
+    fn from_body(body: &str) -> Result<Self, serde_json::Error> {
+        let mut response: Self = serde_json::from_str(body)?;
+        response.expires_at = Some(SystemTime::now() + Duration::from_secs(response.expires_in));
+        Ok(response)
+    }
+
+    /// Whether the access token has expired
+    ///
+    /// Returns `false` when the expiry time is unknown.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= SystemTime::now())
+    }
 }
 
+/// The kind of token passed to [`KickOAuth::revoke_token`]
+///
+/// Sent as the RFC 7009 `token_type_hint` form parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenTypeHint {
+    /// An access token
+    AccessToken,
+    /// A refresh token
+    RefreshToken,
+}
+
+impl TokenTypeHint {
+    /// The hint as sent to Kick (`"access_token"` or `"refresh_token"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenTypeHint::AccessToken => "access_token",
+            TokenTypeHint::RefreshToken => "refresh_token",
+        }
+    }
+}
+
+const KICK_OAUTH_URL: &str = "https://id.kick.com";
+
 /// Holds OAuth credentials and client for Kick.com
 pub struct KickOAuth {
     client: BasicClient,
+    http_client: reqwest::Client,
+    base_url: String,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
 }
 
 impl KickOAuth {
@@ -50,20 +111,82 @@ impl KickOAuth {
             return Err("One or more OAuth credentials are empty!".into());
         }
 
+        Self::new(client_id, client_secret, redirect_uri)
+    }
+
+    pub(crate) fn new(
+        client_id: String,
+        client_secret: String,
+        redirect_uri: String,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         // Kick's OAuth endpoints
-        let auth_url = AuthUrl::new("https://id.kick.com/oauth/authorize".to_string())?;
-        let token_url = TokenUrl::new("https://id.kick.com/oauth/token".to_string())?;
+        let auth_url = AuthUrl::new(format!("{KICK_OAUTH_URL}/oauth/authorize"))?;
+        let token_url = TokenUrl::new(format!("{KICK_OAUTH_URL}/oauth/token"))?;
 
         // Build the OAuth2 client (oauth2 4.4 API)
         let client = BasicClient::new(
-            ClientId::new(client_id),
-            Some(ClientSecret::new(client_secret)),
+            ClientId::new(client_id.clone()),
+            Some(ClientSecret::new(client_secret.clone())),
             auth_url,
             Some(token_url),
         )
-        .set_redirect_uri(RedirectUrl::new(redirect_uri)?);
+        .set_redirect_uri(RedirectUrl::new(redirect_uri.clone())?);
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            http_client: reqwest::Client::new(),
+            base_url: KICK_OAUTH_URL.to_string(),
+            client_id,
+            client_secret,
+            redirect_uri,
+        })
+    }
+
+    /// Send token and revocation requests to a different base URL
+    ///
+    /// Defaults to `https://id.kick.com`; requests go to `/oauth/token` and
+    /// `/oauth/revoke` under it. Point this at a local mock server to test
+    /// token refreshes without reaching Kick. The authorization URL from
+    /// [`get_authorization_url`](Self::get_authorization_url) is unaffected.
+    /// A trailing slash is ignored.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickOAuth;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = KickOAuth::from_env()?.with_base_url("http://127.0.0.1:8080");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Use a custom `reqwest::Client` for token requests
+    ///
+    /// By default one client is created in `from_env()` and shared by every
+    /// token request. Pass your own to share a connection pool with the rest
+    /// of your application or to configure proxies and timeouts.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use kick_api::KickOAuth;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let http = reqwest::Client::builder()
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()?;
+    /// let oauth = KickOAuth::from_env()?.with_client(http);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = client;
+        self
     }
 
     /// Generates the authorization URL that users should visit
@@ -74,11 +197,15 @@ impl KickOAuth {
     /// - auth_url: The URL to send the user to
     /// - csrf_token: Save this! You'll verify it matches when they return
     /// - pkce_verifier: REQUIRED! Pass this to exchange_code() later
-    pub fn get_authorization_url(&self, scopes: Vec<&str>) -> (String, CsrfToken, PkceCodeVerifier) {
+    pub fn get_authorization_url(
+        &self,
+        scopes: Vec<&str>,
+    ) -> (String, CsrfToken, PkceCodeVerifier) {
         // Generate PKCE challenge (required by Kick)
         let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
 
-        let mut auth_request = self.client
+        let mut auth_request = self
+            .client
             .authorize_url(CsrfToken::new_random)
             .set_pkce_challenge(pkce_challenge);
 
@@ -92,6 +219,29 @@ impl KickOAuth {
         (auth_url.to_string(), csrf_token, pkce_verifier)
     }
 
+    /// Like [`get_authorization_url`](Self::get_authorization_url), but takes
+    /// typed scopes so a misspelled scope fails to compile
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::KickOAuth;
+    /// use kick_api::scopes::Scope;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = KickOAuth::from_env()?;
+    /// let (url, _csrf, _verifier) =
+    ///     oauth.get_authorization_url_typed(&[Scope::UserRead, Scope::ChatWrite]);
+    /// println!("Visit: {}", url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_authorization_url_typed(
+        &self,
+        scopes: &[crate::scopes::Scope],
+    ) -> (String, CsrfToken, PkceCodeVerifier) {
+        self.get_authorization_url(scopes.iter().map(|scope| scope.as_str()).collect())
+    }
+
     /// Exchanges the authorization code for an access token
     ///
     /// After the user authorizes, Kick redirects to your callback with a `code` parameter.
@@ -102,20 +252,16 @@ impl KickOAuth {
         &self,
         code: String,
         pkce_verifier: PkceCodeVerifier,
-    ) -> Result<OAuthTokenResponse, Box<dyn std::error::Error>> {
-        let client_id = env::var("KICK_CLIENT_ID")?;
-        let client_secret = env::var("KICK_CLIENT_SECRET")?;
-        let redirect_uri = env::var("KICK_REDIRECT_URI")?;
-
-        let http_client = reqwest::Client::new();
-        let response = http_client
-            .post("https://id.kick.com/oauth/token")
+    ) -> crate::Result<OAuthTokenResponse> {
+        let response = self
+            .http_client
+            .post(format!("{}/oauth/token", self.base_url))
             .form(&[
                 ("grant_type", "authorization_code"),
                 ("code", &code),
-                ("client_id", &client_id),
-                ("client_secret", &client_secret),
-                ("redirect_uri", &redirect_uri),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+                ("redirect_uri", &self.redirect_uri),
                 ("code_verifier", pkce_verifier.secret()),
             ])
             .send()
@@ -125,10 +271,10 @@ impl KickOAuth {
         let body = response.text().await?;
 
         if status.is_success() {
-            let token_response: OAuthTokenResponse = serde_json::from_str(&body)?;
+            let token_response = OAuthTokenResponse::from_body(&body)?;
             Ok(token_response)
         } else {
-            Err(format!("Token exchange failed: {}", body).into())
+            Err(oauth_error(status, body, "Token exchange failed"))
         }
     }
 
@@ -139,21 +285,15 @@ impl KickOAuth {
     ///
     /// # Parameters
     /// - `refresh_token`: The refresh token from a previous token response
-    pub async fn refresh_token(
-        &self,
-        refresh_token: &str,
-    ) -> Result<OAuthTokenResponse, Box<dyn std::error::Error>> {
-        let client_id = env::var("KICK_CLIENT_ID")?;
-        let client_secret = env::var("KICK_CLIENT_SECRET")?;
-
-        let http_client = reqwest::Client::new();
-        let response = http_client
-            .post("https://id.kick.com/oauth/token")
+    pub async fn refresh_token(&self, refresh_token: &str) -> crate::Result<OAuthTokenResponse> {
+        let response = self
+            .http_client
+            .post(format!("{}/oauth/token", self.base_url))
             .form(&[
                 ("grant_type", "refresh_token"),
                 ("refresh_token", refresh_token),
-                ("client_id", &client_id),
-                ("client_secret", &client_secret),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
             ])
             .send()
             .await?;
@@ -162,10 +302,52 @@ impl KickOAuth {
         let body = response.text().await?;
 
         if status.is_success() {
-            let token_response: OAuthTokenResponse = serde_json::from_str(&body)?;
+            let token_response = OAuthTokenResponse::from_body(&body)?;
             Ok(token_response)
         } else {
-            Err(format!("Token refresh failed: {}", body).into())
+            Err(oauth_error(status, body, "Token refresh failed"))
+        }
+    }
+
+    /// Get an app access token using the client credentials grant
+    ///
+    /// App tokens aren't tied to a user, so they need no browser redirect.
+    /// Use them for app-level calls such as reading public channel data;
+    /// endpoints that act on behalf of a user still need a user token from
+    /// `exchange_code()`. The response has no refresh token, so request a
+    /// new one once it expires.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{KickApiClient, KickOAuth};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = KickOAuth::from_env()?;
+    /// let token = oauth.get_app_access_token().await?;
+    /// let client = KickApiClient::with_token(token.access_token);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_app_access_token(&self) -> crate::Result<OAuthTokenResponse> {
+        let response = self
+            .http_client
+            .post(format!("{}/oauth/token", self.base_url))
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", &self.client_id),
+                ("client_secret", &self.client_secret),
+            ])
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+
+        if status.is_success() {
+            let token_response = OAuthTokenResponse::from_body(&body)?;
+            Ok(token_response)
+        } else {
+            Err(oauth_error(status, body, "App access token request failed"))
         }
     }
 
@@ -175,21 +357,38 @@ impl KickOAuth {
     ///
     /// # Parameters
     /// - `token`: The access token or refresh token to revoke
+    /// - `hint`: Which kind of token `token` is, if known. Lets Kick look it
+    ///   up faster; pass `None` when unsure.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use kick_api::{KickOAuth, TokenTypeHint};
+    ///
+    /// # async fn example(oauth: KickOAuth, refresh_token: String) -> kick_api::Result<()> {
+    /// oauth
+    ///     .revoke_token(&refresh_token, Some(TokenTypeHint::RefreshToken))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn revoke_token(
         &self,
         token: &str,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let client_id = env::var("KICK_CLIENT_ID")?;
-        let client_secret = env::var("KICK_CLIENT_SECRET")?;
+        hint: Option<TokenTypeHint>,
+    ) -> crate::Result<()> {
+        let mut form = vec![
+            ("token", token),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        if let Some(hint) = hint {
+            form.push(("token_type_hint", hint.as_str()));
+        }
 
-        let http_client = reqwest::Client::new();
-        let response = http_client
-            .post("https://id.kick.com/oauth/revoke")
-            .form(&[
-                ("token", token),
-                ("client_id", &client_id),
-                ("client_secret", &client_secret),
-            ])
+        let response = self
+            .http_client
+            .post(format!("{}/oauth/revoke", self.base_url))
+            .form(&form)
             .send()
             .await?;
 
@@ -198,15 +397,92 @@ impl KickOAuth {
             Ok(())
         } else {
             let body = response.text().await?;
-            Err(format!("Token revocation failed: {}", body).into())
+            Err(oauth_error(status, body, "Token revocation failed"))
         }
     }
 }
 
+/// Build the error for a failed OAuth request
+///
+/// Kick's OAuth server answers with a standard OAuth error body
+/// (`{"error": "invalid_grant", "error_description": "..."}`); anything
+/// else falls back to the generic status error.
+fn oauth_error(status: StatusCode, body: String, context: &str) -> KickApiError {
+    #[derive(Deserialize)]
+    struct ErrorBody {
+        error: String,
+        error_description: Option<String>,
+    }
+
+    match serde_json::from_str::<ErrorBody>(&body) {
+        Ok(parsed) => KickApiError::OAuth {
+            error: parsed.error,
+            description: parsed.error_description,
+        },
+        Err(_) => crate::http::status_error(status, body, context),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_token_response_expires_at() {
+        let body = r#"{"access_token":"a","refresh_token":"r","expires_in":3600,"scope":"user:read","token_type":"Bearer"}"#;
+        let response = OAuthTokenResponse::from_body(body).unwrap();
+        let expires_at = response.expires_at.unwrap();
+        assert!(expires_at > SystemTime::now() + Duration::from_secs(3500));
+        assert!(!response.is_expired());
+
+        let expired = OAuthTokenResponse {
+            expires_at: Some(SystemTime::now() - Duration::from_secs(1)),
+            ..response
+        };
+        assert!(expired.is_expired());
+    }
+
+    #[test]
+    fn test_token_response_round_trip_keeps_expiry() {
+        let body = r#"{"access_token":"a","expires_in":3600,"token_type":"Bearer"}"#;
+        let response = OAuthTokenResponse::from_body(body).unwrap();
+
+        let saved = serde_json::to_string(&response).unwrap();
+        let loaded: OAuthTokenResponse = serde_json::from_str(&saved).unwrap();
+        assert_eq!(loaded.expires_at, response.expires_at);
+
+        let expired = OAuthTokenResponse {
+            expires_at: Some(SystemTime::now() - Duration::from_secs(1)),
+            ..loaded
+        };
+        let saved = serde_json::to_string(&expired).unwrap();
+        let loaded: OAuthTokenResponse = serde_json::from_str(&saved).unwrap();
+        assert!(loaded.is_expired());
+    }
+
+    #[test]
+    fn test_oauth_error_body() {
+        let body = r#"{"error":"invalid_grant","error_description":"Refresh token expired"}"#;
+        match oauth_error(
+            StatusCode::BAD_REQUEST,
+            body.to_string(),
+            "Token refresh failed",
+        ) {
+            KickApiError::OAuth { error, description } => {
+                assert_eq!(error, "invalid_grant");
+                assert_eq!(description.as_deref(), Some("Refresh token expired"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+
+        let err = oauth_error(
+            StatusCode::BAD_GATEWAY,
+            "<html>".to_string(),
+            "Token refresh failed",
+        );
+        assert!(matches!(err, KickApiError::Api { status: 502, .. }));
+    }
+
     #[test]
     fn test_oauth_from_env() {
         // This will fail if env vars aren't set - that's expected
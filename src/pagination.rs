@@ -0,0 +1,113 @@
+//! Lazy iteration over cursor-paginated list endpoints
+
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::{self, BoxStream, Stream, StreamExt};
+
+use crate::error::Result;
+use crate::models::Page;
+
+/// Stream of every item from a cursor-paginated endpoint
+///
+/// Pages are fetched one at a time as the stream is polled, so stopping
+/// early skips the remaining requests. A failed page fetch is yielded as an
+/// `Err` and ends the stream. A cursor that was already seen also ends it,
+/// so a misbehaving endpoint can't loop forever.
+///
+/// # Example
+/// ```no_run
+/// use futures_util::StreamExt;
+///
+/// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+/// let rewards = client.rewards();
+/// let mut redemptions = rewards.paginate_redemptions(None, None);
+/// while let Some(redemption) = redemptions.next().await {
+///     println!("{}", redemption?.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<'a, T> {
+    items: BoxStream<'a, Result<T>>,
+}
+
+impl<'a, T: Send + 'a> Paginator<'a, T> {
+    /// Page through an endpoint, calling `fetch` with each page's cursor
+    ///
+    /// `fetch` gets `None` for the first page.
+    pub(crate) fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'a,
+    {
+        // `None` once the last page has been fetched
+        let start: Option<Option<String>> = Some(None);
+
+        let pages = stream::unfold(
+            (fetch, start, HashSet::new()),
+            |(mut fetch, cursor, mut seen)| async move {
+                let cursor = cursor?;
+                match fetch(cursor).await {
+                    Ok(page) => {
+                        let next = page.next_cursor.filter(|next| seen.insert(next.clone()));
+                        Some((Ok(page.items), (fetch, next.map(Some), seen)))
+                    }
+                    Err(e) => Some((Err(e), (fetch, None, seen))),
+                }
+            },
+        );
+
+        let items = pages.flat_map(|page| match page {
+            Ok(items) => stream::iter(items.into_iter().map(Ok)).left_stream(),
+            Err(e) => stream::once(async { Err(e) }).right_stream(),
+        });
+
+        Self {
+            items: items.boxed(),
+        }
+    }
+
+    /// Collect every remaining item, failing on the first error
+    pub async fn collect_all(self) -> Result<Vec<T>> {
+        futures_util::TryStreamExt::try_collect(self).await
+    }
+}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_paginator_follows_cursors() {
+        let paginator = Paginator::new(|cursor: Option<String>| async move {
+            let page = match cursor.as_deref() {
+                None => Page {
+                    items: vec![1, 2],
+                    next_cursor: Some("a".to_string()),
+                },
+                Some("a") => Page {
+                    items: vec![3],
+                    next_cursor: Some("b".to_string()),
+                },
+                // Repeats an earlier cursor, which must end the stream
+                _ => Page {
+                    items: vec![4],
+                    next_cursor: Some("a".to_string()),
+                },
+            };
+            Ok(page)
+        });
+        assert_eq!(paginator.collect_all().await.unwrap(), [1, 2, 3, 4]);
+    }
+}
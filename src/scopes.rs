@@ -0,0 +1,132 @@
+//! Kick OAuth scopes and helpers for parsing them from configuration
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{KickApiError, Result};
+
+/// An OAuth scope understood by Kick's public API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// `user:read` - read user information
+    UserRead,
+    /// `channel:read` - read channel information
+    ChannelRead,
+    /// `channel:write` - update channel metadata
+    ChannelWrite,
+    /// `channel:rewards:read` - read channel rewards and redemptions
+    ChannelRewardsRead,
+    /// `channel:rewards:write` - manage channel rewards and redemptions
+    ChannelRewardsWrite,
+    /// `chat:write` - send chat messages
+    ChatWrite,
+    /// `streamkey:read` - read the stream key
+    StreamkeyRead,
+    /// `events:subscribe` - subscribe to webhook events
+    EventsSubscribe,
+    /// `moderation:ban` - ban and unban users
+    ModerationBan,
+    /// `moderation:chat_message:manage` - delete chat messages
+    ModerationChatMessageManage,
+    /// `kicks:read` - read KICKs data
+    KicksRead,
+}
+
+impl Scope {
+    /// Every known scope
+    pub const ALL: [Scope; 11] = [
+        Scope::UserRead,
+        Scope::ChannelRead,
+        Scope::ChannelWrite,
+        Scope::ChannelRewardsRead,
+        Scope::ChannelRewardsWrite,
+        Scope::ChatWrite,
+        Scope::StreamkeyRead,
+        Scope::EventsSubscribe,
+        Scope::ModerationBan,
+        Scope::ModerationChatMessageManage,
+        Scope::KicksRead,
+    ];
+
+    /// The scope string as used by Kick (e.g. `"chat:write"`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::UserRead => "user:read",
+            Scope::ChannelRead => "channel:read",
+            Scope::ChannelWrite => "channel:write",
+            Scope::ChannelRewardsRead => "channel:rewards:read",
+            Scope::ChannelRewardsWrite => "channel:rewards:write",
+            Scope::ChatWrite => "chat:write",
+            Scope::StreamkeyRead => "streamkey:read",
+            Scope::EventsSubscribe => "events:subscribe",
+            Scope::ModerationBan => "moderation:ban",
+            Scope::ModerationChatMessageManage => "moderation:chat_message:manage",
+            Scope::KicksRead => "kicks:read",
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Scope {
+    type Err = KickApiError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Scope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| KickApiError::InvalidInput(format!("Unknown scope '{s}'")))
+    }
+}
+
+/// Parse a list of scopes from a config string
+///
+/// Scopes may be separated by whitespace and/or commas. Duplicates are
+/// dropped, keeping the first occurrence. Any unknown scope fails the whole
+/// parse with [`KickApiError::InvalidInput`] naming it.
+///
+/// # Example
+/// ```
+/// use kick_api::scopes::{self, Scope};
+///
+/// let parsed = scopes::parse("user:read, chat:write user:read")?;
+/// assert_eq!(parsed, [Scope::UserRead, Scope::ChatWrite]);
+///
+/// let names: Vec<&str> = parsed.iter().map(Scope::as_str).collect();
+/// // pass `names` to `KickOAuth::get_authorization_url`
+/// # Ok::<(), kick_api::KickApiError>(())
+/// ```
+pub fn parse(s: &str) -> Result<Vec<Scope>> {
+    let mut scopes = Vec::new();
+    for part in s.split(|c: char| c == ',' || c.is_whitespace()) {
+        if part.is_empty() {
+            continue;
+        }
+        let scope: Scope = part.parse()?;
+        if !scopes.contains(&scope) {
+            scopes.push(scope);
+        }
+    }
+    Ok(scopes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scopes() {
+        let parsed = parse(" channel:read,\tchat:write ,, channel:read\n").unwrap();
+        assert_eq!(parsed, [Scope::ChannelRead, Scope::ChatWrite]);
+        assert!(parse("").unwrap().is_empty());
+
+        match parse("user:read chat:wirte") {
+            Err(KickApiError::InvalidInput(msg)) => assert_eq!(msg, "Unknown scope 'chat:wirte'"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
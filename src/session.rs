@@ -0,0 +1,321 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::error::{KickApiError, Result};
+use crate::oauth::{KickOAuth, OAuthTokenResponse};
+use crate::scopes::Scope;
+
+/// Refresh this long before the token expires, so in-flight requests
+/// don't race the expiry
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// An automatic token refresh that failed, passed to the callback set with
+/// [`KickApiClient::on_refresh_failure`](crate::KickApiClient::on_refresh_failure)
+///
+/// The request that needed the refresh fails with the same error.
+#[derive(Debug)]
+pub struct RefreshFailure<'a> {
+    error: &'a KickApiError,
+}
+
+impl RefreshFailure<'_> {
+    /// The error the refresh failed with
+    pub fn error(&self) -> &KickApiError {
+        self.error
+    }
+
+    /// Whether Kick rejected the refresh token (`invalid_grant`), so the
+    /// user has to authorize again
+    ///
+    /// Other failures, such as network errors or Kick outages, may go away
+    /// on a later refresh.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.error, KickApiError::OAuth { error, .. } if error == "invalid_grant")
+    }
+}
+
+type RefreshFailureCallback = Arc<dyn Fn(&RefreshFailure<'_>) + Send + Sync>;
+
+/// Credentials shared by all clones of a [`KickApiClient`](crate::KickApiClient)
+///
+/// Holds the access token and, when created from an OAuth token response,
+/// what is needed to refresh it. Refreshes are serialized so concurrent
+/// requests that hit an expired token trigger a single refresh.
+///
+/// Optionally also holds the scopes granted to the token, so API methods
+/// can fail fast on a missing scope instead of waiting for a 403.
+pub(crate) struct Session {
+    state: Mutex<TokenState>,
+    oauth: Option<KickOAuth>,
+    refresh_lock: tokio::sync::Mutex<()>,
+    granted_scopes: Mutex<Option<HashSet<String>>>,
+    on_refresh_failure: Mutex<Option<RefreshFailureCallback>>,
+}
+
+#[derive(Default)]
+struct TokenState {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_at: Option<Instant>,
+}
+
+impl TokenState {
+    fn from_response(response: OAuthTokenResponse, previous_refresh: Option<String>) -> Self {
+        Self {
+            access_token: Some(response.access_token),
+            // Kick may omit the refresh token when it isn't rotated
+            refresh_token: response.refresh_token.or(previous_refresh),
+            expires_at: Some(Instant::now() + Duration::from_secs(response.expires_in)),
+        }
+    }
+}
+
+impl Session {
+    /// A session with a fixed access token (or none) that never refreshes
+    pub(crate) fn new(access_token: Option<String>) -> Self {
+        Self {
+            state: Mutex::new(TokenState {
+                access_token,
+                ..TokenState::default()
+            }),
+            oauth: None,
+            refresh_lock: tokio::sync::Mutex::new(()),
+            granted_scopes: Mutex::new(None),
+            on_refresh_failure: Mutex::new(None),
+        }
+    }
+
+    /// A session that refreshes its token through `oauth`
+    pub(crate) fn with_oauth(oauth: KickOAuth, token: OAuthTokenResponse) -> Self {
+        Self {
+            state: Mutex::new(TokenState::from_response(token, None)),
+            oauth: Some(oauth),
+            refresh_lock: tokio::sync::Mutex::new(()),
+            granted_scopes: Mutex::new(None),
+            on_refresh_failure: Mutex::new(None),
+        }
+    }
+
+    /// The current access token, if any
+    pub(crate) fn access_token(&self) -> Option<String> {
+        self.state().access_token.clone()
+    }
+
+    /// Replace the access token
+    ///
+    /// The new token's expiry is unknown, so it is only refreshed after a
+    /// 401. Any refresh token is kept.
+    pub(crate) fn set_access_token(&self, access_token: Option<String>) {
+        let mut state = self.state();
+        state.access_token = access_token;
+        state.expires_at = None;
+    }
+
+    /// Whether a 401 can be recovered from by refreshing the token
+    pub(crate) fn can_refresh(&self) -> bool {
+        self.oauth.is_some() && self.state().refresh_token.is_some()
+    }
+
+    /// Get a token for an authenticated request
+    ///
+    /// Refreshes first if the token is about to expire and a refresh token
+    /// is available.
+    pub(crate) async fn bearer_token(&self) -> Result<String> {
+        let (token, expiring) = {
+            let state = self.state();
+            let token = state.access_token.clone().ok_or_else(|| {
+                KickApiError::ApiError(
+                    "OAuth token required for this endpoint; for public data an app access \
+                     token from KickOAuth::get_app_access_token is enough"
+                        .to_string(),
+                )
+            })?;
+            let expiring = state
+                .expires_at
+                .is_some_and(|at| at.saturating_duration_since(Instant::now()) <= EXPIRY_MARGIN);
+            (token, expiring)
+        };
+
+        if expiring && self.can_refresh() {
+            self.refresh(&token).await
+        } else {
+            Ok(token)
+        }
+    }
+
+    /// Refresh the token, unless it already changed since `stale_token`
+    /// was read
+    ///
+    /// Callers that raced on the same stale token wait for the first
+    /// refresh and then get its result instead of refreshing again.
+    pub(crate) async fn refresh(&self, stale_token: &str) -> Result<String> {
+        let _guard = self.refresh_lock.lock().await;
+
+        let refresh_token = {
+            let state = self.state();
+            if let Some(current) = &state.access_token
+                && current != stale_token
+            {
+                return Ok(current.clone());
+            }
+            state.refresh_token.clone()
+        };
+
+        let (Some(oauth), Some(refresh_token)) = (&self.oauth, refresh_token) else {
+            return Err(KickApiError::ApiError(
+                "Token expired and no refresh token is available".to_string(),
+            ));
+        };
+
+        let response = match oauth.refresh_token(&refresh_token).await {
+            Ok(response) => response,
+            Err(error) => {
+                self.report_refresh_failure(&error);
+                return Err(error);
+            }
+        };
+        let new_state = TokenState::from_response(response, Some(refresh_token));
+        let token = new_state.access_token.clone().unwrap_or_default();
+        *self.state() = new_state;
+
+        Ok(token)
+    }
+
+    /// Set the callback run when a refresh fails
+    pub(crate) fn set_on_refresh_failure(&self, callback: RefreshFailureCallback) {
+        *self
+            .on_refresh_failure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(callback);
+    }
+
+    fn report_refresh_failure(&self, error: &KickApiError) {
+        // Clone the callback out so it doesn't run under the lock
+        let callback = self
+            .on_refresh_failure
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(callback) = callback {
+            callback(&RefreshFailure { error });
+        }
+    }
+
+    /// Set the scopes granted to the token, or `None` to stop checking
+    pub(crate) fn set_granted_scopes(&self, scopes: Option<HashSet<String>>) {
+        *self
+            .granted_scopes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = scopes;
+    }
+
+    /// Fail with [`KickApiError::InsufficientScope`] if granted scopes are
+    /// known and `scope` isn't one of them
+    ///
+    /// Always succeeds when no granted scopes were set.
+    pub(crate) fn require_scope(&self, scope: Scope) -> Result<()> {
+        let granted = self
+            .granted_scopes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        match granted.as_ref() {
+            Some(granted) => check_scope(granted, scope),
+            None => Ok(()),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, TokenState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Fail with [`KickApiError::InsufficientScope`] unless `granted` has `scope`
+pub(crate) fn check_scope(granted: &HashSet<String>, scope: Scope) -> Result<()> {
+    if granted.contains(scope.as_str()) {
+        return Ok(());
+    }
+    let mut granted: Vec<String> = granted.iter().cloned().collect();
+    granted.sort();
+    Err(KickApiError::InsufficientScope {
+        required: scope,
+        granted,
+    })
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Never print the tokens themselves
+        let state = self.state();
+        f.debug_struct("Session")
+            .field("has_access_token", &state.access_token.is_some())
+            .field(
+                "can_refresh",
+                &(self.oauth.is_some() && state.refresh_token.is_some()),
+            )
+            .field("expires_at", &state.expires_at)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_static_session_tokens() {
+        let anonymous = Session::new(None);
+        assert!(anonymous.bearer_token().await.is_err());
+        assert!(!anonymous.can_refresh());
+
+        let session = Session::new(Some("abc".to_string()));
+        assert_eq!(session.bearer_token().await.unwrap(), "abc");
+
+        // A stale token that was already replaced resolves to the current one
+        assert_eq!(session.refresh("old").await.unwrap(), "abc");
+        // Without OAuth credentials the current token can't be refreshed
+        assert!(session.refresh("abc").await.is_err());
+
+        assert!(!format!("{session:?}").contains("abc"));
+
+        session.set_access_token(Some("def".to_string()));
+        assert_eq!(session.bearer_token().await.unwrap(), "def");
+        session.set_access_token(None);
+        assert!(session.bearer_token().await.is_err());
+    }
+
+    #[test]
+    fn test_require_scope() {
+        let session = Session::new(Some("abc".to_string()));
+        // Nothing is checked until granted scopes are known
+        assert!(session.require_scope(Scope::ChatWrite).is_ok());
+
+        session.set_granted_scopes(Some(HashSet::from(["user:read".to_string()])));
+        assert!(session.require_scope(Scope::UserRead).is_ok());
+        match session.require_scope(Scope::ChatWrite) {
+            Err(KickApiError::InsufficientScope { required, granted }) => {
+                assert_eq!(required, Scope::ChatWrite);
+                assert_eq!(granted, ["user:read"]);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_refresh_failure_is_terminal() {
+        let revoked = KickApiError::OAuth {
+            error: "invalid_grant".to_string(),
+            description: None,
+        };
+        assert!(RefreshFailure { error: &revoked }.is_terminal());
+
+        let outage = KickApiError::Api {
+            status: 503,
+            body: String::new(),
+            message: None,
+            retry_after: None,
+        };
+        assert!(!RefreshFailure { error: &outage }.is_terminal());
+    }
+}
@@ -0,0 +1,167 @@
+//! Minimal timestamp parsing for the ISO 8601 strings Kick returns and
+//! HTTP dates
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Parse an RFC 3339 timestamp such as `2024-01-01T12:30:00Z` or
+/// `2024-01-01T12:30:00.123+02:00`
+///
+/// A space is accepted in place of the `T` separator, and a missing offset is
+/// treated as UTC. Returns `None` for malformed input and for times before
+/// the Unix epoch.
+pub(crate) fn parse_rfc3339(s: &str) -> Option<SystemTime> {
+    let s = s.trim();
+    // The checks below are by byte position, so slicing is only safe on ASCII
+    if !s.is_ascii() {
+        return None;
+    }
+    let b = s.as_bytes();
+    if b.len() < 19 || b[4] != b'-' || b[7] != b'-' || b[13] != b':' || b[16] != b':' {
+        return None;
+    }
+    if !matches!(b[10], b'T' | b't' | b' ') {
+        return None;
+    }
+
+    let year: i64 = digits(&s[0..4])?;
+    let month: u32 = digits(&s[5..7])?;
+    let day: u32 = digits(&s[8..10])?;
+    let hour: i64 = digits(&s[11..13])?;
+    let minute: i64 = digits(&s[14..16])?;
+    let second: i64 = digits(&s[17..19])?;
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut nanos = 0u32;
+    if let Some(frac) = rest.strip_prefix('.') {
+        let len = frac.bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        let padded = format!("{:0<9}", &frac[..len.min(9)]);
+        nanos = digits(&padded)?;
+        rest = &frac[len..];
+    }
+
+    let offset_secs: i64 = match rest {
+        "" | "Z" | "z" => 0,
+        _ => {
+            let rb = rest.as_bytes();
+            if rb.len() != 6 || rb[3] != b':' {
+                return None;
+            }
+            let sign = match rb[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let h: i64 = digits(&rest[1..3])?;
+            let m: i64 = digits(&rest[4..6])?;
+            sign * (h * 3600 + m * 60)
+        }
+    };
+
+    let secs = days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second
+        - offset_secs;
+    let secs = u64::try_from(secs).ok()?;
+    Some(UNIX_EPOCH + Duration::new(secs, nanos))
+}
+
+/// Parse an RFC 3339 timestamp as a chrono UTC timestamp
+///
+/// Like [`parse_rfc3339`], a missing offset is treated as UTC.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_utc(s: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let s = s.trim();
+    match chrono::DateTime::parse_from_rfc3339(s) {
+        Ok(time) => Some(time.with_timezone(&chrono::Utc)),
+        Err(_) => ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(s, format).ok())
+            .map(|time| time.and_utc()),
+    }
+}
+
+/// Parse an HTTP date in the preferred IMF-fixdate format, such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`
+///
+/// The obsolete RFC 850 and asctime formats are not supported.
+pub(crate) fn parse_http_date(s: &str) -> Option<SystemTime> {
+    let mut parts = s.trim().split(' ');
+    let (_weekday, day, month, year, time, zone) = (
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+        parts.next()?,
+    );
+    if parts.next().is_some() || zone != "GMT" || day.len() != 2 || year.len() != 4 {
+        return None;
+    }
+
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = MONTHS.iter().position(|m| *m == month)? + 1;
+
+    parse_rfc3339(&format!("{year}-{month:02}-{day}T{time}Z"))
+}
+
+fn digits<T: std::str::FromStr>(s: &str) -> Option<T> {
+    if s.bytes().all(|c| c.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc3339() {
+        let secs =
+            |s: &str| parse_rfc3339(s).map(|t| t.duration_since(UNIX_EPOCH).unwrap().as_secs_f64());
+
+        assert_eq!(secs("1970-01-01T00:00:00Z"), Some(0.0));
+        assert_eq!(secs("2024-01-01T00:00:00Z"), Some(1_704_067_200.0));
+        assert_eq!(secs("2024-01-01 00:00:00"), Some(1_704_067_200.0));
+        assert_eq!(secs("2024-02-29T12:00:00.5Z"), Some(1_709_208_000.5));
+        assert_eq!(secs("2024-01-01T02:00:00+02:00"), Some(1_704_067_200.0));
+        assert_eq!(secs("0001-01-01T00:00:00Z"), None);
+        assert_eq!(secs("yesterday"), None);
+        assert_eq!(secs("2024-13-01T00:00:00Z"), None);
+        assert_eq!(secs("2024-01-01T00:00:0é"), None);
+        assert_eq!(secs("2024-01-01T00:00:00+0é:00"), None);
+    }
+
+    #[test]
+    fn test_parse_http_date() {
+        let expected = parse_rfc3339("1994-11-06T08:49:37Z");
+        assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), expected);
+        assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("Sun, 06 Foo 1994 08:49:37 GMT"), None);
+        assert_eq!(parse_http_date("120"), None);
+        assert_eq!(parse_http_date("Sun, é6 Nov 1994 08:49:37 GMT"), None);
+    }
+}
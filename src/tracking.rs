@@ -0,0 +1,262 @@
+//! Client-side aggregations built on top of polled API data and live chat
+
+use std::collections::{HashSet, VecDeque};
+use std::time::SystemTime;
+
+use crate::models::{Category, Channel, LiveChatMessage};
+
+/// A category transition observed by [`CategoryTracker`]
+#[derive(Debug, Clone)]
+pub struct CategoryChange {
+    /// The category the channel switched to, or `None` when it went offline
+    pub category: Option<Category>,
+
+    /// When the transition was observed
+    pub at: SystemTime,
+}
+
+/// Records which categories a channel streamed in over time.
+///
+/// Kick doesn't expose a channel's category history, so this builds one from
+/// periodic channel snapshots: feed it the result of each
+/// `client.channels().get(slug)` poll and it records a [`CategoryChange`]
+/// whenever the live category differs from the last one seen. Going offline
+/// is recorded as a change to `None`.
+///
+/// The history is bounded: once `capacity` changes are stored, the oldest
+/// are dropped.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use kick_api::CategoryTracker;
+///
+/// # async fn example(client: kick_api::KickApiClient) -> kick_api::Result<()> {
+/// let mut tracker = CategoryTracker::new(100);
+/// loop {
+///     let channel = client.channels().get("xqc").await?;
+///     tracker.record(&channel);
+///
+///     for change in tracker.history() {
+///         let name = change.category.as_ref().map(|c| c.name.as_str());
+///         println!("{:?}: {}", change.at, name.unwrap_or("offline"));
+///     }
+///     tokio::time::sleep(Duration::from_secs(60)).await;
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CategoryTracker {
+    capacity: usize,
+    history: VecDeque<CategoryChange>,
+}
+
+impl CategoryTracker {
+    /// Create a tracker that keeps at most `capacity` transitions
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Record a channel snapshot taken now
+    ///
+    /// Returns `true` if the snapshot produced a new transition.
+    pub fn record(&mut self, channel: &Channel) -> bool {
+        self.record_at(channel, SystemTime::now())
+    }
+
+    /// Record a channel snapshot taken at a specific time
+    ///
+    /// Returns `true` if the snapshot produced a new transition.
+    pub fn record_at(&mut self, channel: &Channel, at: SystemTime) -> bool {
+        let is_live = channel.stream.as_ref().is_some_and(|s| s.is_live);
+        let category = if is_live {
+            channel.category.clone()
+        } else {
+            None
+        };
+
+        let current_id = self.current().map(|c| c.id);
+        let unchanged = match self.history.back() {
+            Some(_) => current_id == category.as_ref().map(|c| c.id),
+            // Nothing worth recording until the channel is first seen live
+            None => category.is_none(),
+        };
+        if unchanged {
+            return false;
+        }
+
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(CategoryChange { category, at });
+        true
+    }
+
+    /// The category the channel was last seen streaming in
+    ///
+    /// Returns `None` if the channel is offline or hasn't been seen live.
+    pub fn current(&self) -> Option<&Category> {
+        self.history.back().and_then(|c| c.category.as_ref())
+    }
+
+    /// Recorded transitions, oldest first
+    pub fn history(&self) -> impl Iterator<Item = &CategoryChange> {
+        self.history.iter()
+    }
+
+    /// Remove all recorded transitions
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+}
+
+/// Detects the first message each chatter sends during a session.
+///
+/// Kick's chat payloads carry no "first message" flag, so this remembers
+/// the sender IDs it has seen. Novelty is per tracker, not per channel
+/// history: a regular who chatted before the tracker was created is still
+/// reported as new on their first message of the session.
+///
+/// # Example
+/// ```no_run
+/// use kick_api::{FirstChatterTracker, LiveChatClient};
+///
+/// # async fn example() -> kick_api::Result<()> {
+/// let mut chat = LiveChatClient::connect(27670567).await?;
+/// let mut first_chatters = FirstChatterTracker::new();
+/// while let Some(msg) = chat.next_message().await? {
+///     if first_chatters.observe(&msg) {
+///         println!("Welcome, {}!", msg.sender.username);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FirstChatterTracker {
+    seen: HashSet<u64>,
+}
+
+impl FirstChatterTracker {
+    /// Create an empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a message's sender
+    ///
+    /// Returns `true` if this is the first message seen from them.
+    pub fn observe(&mut self, msg: &LiveChatMessage) -> bool {
+        self.observe_user(msg.sender.id)
+    }
+
+    /// Record a sender by user ID
+    ///
+    /// Returns `true` if the user hasn't been seen before.
+    pub fn observe_user(&mut self, user_id: u64) -> bool {
+        self.seen.insert(user_id)
+    }
+
+    /// Whether a user has been seen
+    pub fn has_seen(&self, user_id: u64) -> bool {
+        self.seen.contains(&user_id)
+    }
+
+    /// Number of distinct chatters seen
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Whether no chatters have been seen
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Forget all seen chatters, e.g. when a new stream starts
+    pub fn clear(&mut self) {
+        self.seen.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn channel(category: Option<(u32, &str)>) -> Channel {
+        let mut value = serde_json::json!({
+            "active_subscribers_count": 0,
+            "broadcaster_user_id": 1,
+            "canceled_subscribers_count": 0,
+            "slug": "streamer",
+        });
+        if let Some((id, name)) = category {
+            value["category"] = serde_json::json!({ "id": id, "name": name });
+            value["stream"] = serde_json::json!({
+                "is_live": true,
+                "is_mature": false,
+                "key": "",
+                "language": "en",
+                "start_time": "2024-01-01T00:00:00Z",
+                "url": "",
+                "viewer_count": 10,
+            });
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_records_transitions_only() {
+        let mut tracker = CategoryTracker::new(10);
+        let t = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+
+        assert!(!tracker.record_at(&channel(None), t(0)));
+        assert!(tracker.record_at(&channel(Some((1, "Just Chatting"))), t(1)));
+        assert!(!tracker.record_at(&channel(Some((1, "Just Chatting"))), t(2)));
+        assert!(tracker.record_at(&channel(Some((2, "Fortnite"))), t(3)));
+        assert!(tracker.record_at(&channel(None), t(4)));
+
+        let names: Vec<_> = tracker
+            .history()
+            .map(|c| c.category.as_ref().map(|c| c.name.clone()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                Some("Just Chatting".to_string()),
+                Some("Fortnite".to_string()),
+                None
+            ]
+        );
+        assert!(tracker.current().is_none());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut tracker = CategoryTracker::new(2);
+        for id in 1..=5 {
+            tracker.record(&channel(Some((id, "Game"))));
+        }
+
+        let ids: Vec<_> = tracker
+            .history()
+            .filter_map(|c| c.category.as_ref().map(|c| c.id))
+            .collect();
+        assert_eq!(ids, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_first_chatter_tracker() {
+        let mut tracker = FirstChatterTracker::new();
+        assert!(tracker.observe_user(1));
+        assert!(!tracker.observe_user(1));
+        assert!(tracker.observe_user(2));
+        assert_eq!(tracker.len(), 2);
+
+        tracker.clear();
+        assert!(!tracker.has_seen(1));
+    }
+}
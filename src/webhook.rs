@@ -0,0 +1,249 @@
+//! Helpers for receiving Kick webhooks
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::sha2::Sha256;
+use rsa::signature::Verifier;
+
+use crate::error::{KickApiError, Result};
+use crate::models::WebhookEvent;
+
+/// Header carrying the unique ID of a webhook delivery
+///
+/// Kick reuses the same ID when it redelivers a message.
+pub const MESSAGE_ID_HEADER: &str = "Kick-Event-Message-Id";
+
+/// Header carrying the event type of a webhook delivery (e.g.
+/// `"chat.message.sent"`)
+pub const EVENT_TYPE_HEADER: &str = "Kick-Event-Type";
+
+/// Header carrying the time Kick sent a webhook delivery
+pub const TIMESTAMP_HEADER: &str = "Kick-Event-Message-Timestamp";
+
+/// Header carrying the base64 RSA-SHA256 signature of a webhook delivery
+pub const SIGNATURE_HEADER: &str = "Kick-Event-Signature";
+
+/// Check that a webhook delivery was signed by Kick
+///
+/// Kick signs `{message_id}.{timestamp}.{body}` with RSA-SHA256 (PKCS#1
+/// v1.5). Pass the [`MESSAGE_ID_HEADER`], [`TIMESTAMP_HEADER`] and
+/// [`SIGNATURE_HEADER`] values along with the raw request body, before any
+/// JSON parsing. `public_key_pem` is Kick's public key, as returned by
+/// [`EventsApi::public_key`](crate::EventsApi::public_key).
+///
+/// Returns `Ok(false)` when the signature doesn't match, and an error when
+/// the key or signature can't be decoded.
+///
+/// # Example
+/// ```no_run
+/// # fn example(public_key: &str, headers: &std::collections::HashMap<String, String>, body: &[u8]) -> kick_api::Result<()> {
+/// use kick_api::{MESSAGE_ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, verify_signature};
+///
+/// let valid = verify_signature(
+///     public_key,
+///     &headers[MESSAGE_ID_HEADER],
+///     &headers[TIMESTAMP_HEADER],
+///     body,
+///     &headers[SIGNATURE_HEADER],
+/// )?;
+/// if !valid {
+///     // Reject the request
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn verify_signature(
+    public_key_pem: &str,
+    message_id: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature_b64: &str,
+) -> Result<bool> {
+    let public_key = rsa::RsaPublicKey::from_public_key_pem(public_key_pem.trim())
+        .map_err(|e| KickApiError::InvalidInput(format!("Invalid webhook public key: {}", e)))?;
+    let signature_bytes = BASE64
+        .decode(signature_b64.trim())
+        .map_err(|e| KickApiError::InvalidInput(format!("Invalid webhook signature: {}", e)))?;
+    let signature = Signature::try_from(signature_bytes.as_slice())
+        .map_err(|e| KickApiError::InvalidInput(format!("Invalid webhook signature: {}", e)))?;
+
+    let mut signed = Vec::with_capacity(message_id.len() + timestamp.len() + body.len() + 2);
+    signed.extend_from_slice(message_id.as_bytes());
+    signed.push(b'.');
+    signed.extend_from_slice(timestamp.as_bytes());
+    signed.push(b'.');
+    signed.extend_from_slice(body);
+
+    let verifying_key = VerifyingKey::<Sha256>::new(public_key);
+    Ok(verifying_key.verify(&signed, &signature).is_ok())
+}
+
+/// Parse a webhook delivery into a typed event
+///
+/// `event_type` is the [`EVENT_TYPE_HEADER`] value and `body` the raw
+/// request body. Verify the delivery with [`verify_signature`] first.
+///
+/// # Example
+/// ```no_run
+/// use kick_api::{WebhookEvent, parse_webhook};
+///
+/// # fn example(event_type: &str, body: &[u8]) -> kick_api::Result<()> {
+/// match parse_webhook(event_type, body)? {
+///     WebhookEvent::ChatMessageSent(msg) => println!("{}: {}", msg.sender.username, msg.content),
+///     WebhookEvent::ChannelFollowed(follow) => println!("New follower: {}", follow.follower.username),
+///     WebhookEvent::Unknown { event_type, .. } => println!("Unhandled event {}", event_type),
+///     _ => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_webhook(event_type: &str, body: &[u8]) -> Result<WebhookEvent> {
+    WebhookEvent::parse(event_type, body)
+}
+
+/// Remembers recently seen webhook message IDs to drop redeliveries.
+///
+/// Kick may deliver the same webhook more than once (e.g. when the first
+/// delivery timed out). Pass each delivery's [`MESSAGE_ID_HEADER`] value to
+/// [`seen`](Self::seen) and skip the event when it returns `true`.
+///
+/// IDs are forgotten after `ttl`, and at most `capacity` IDs are kept; once
+/// full, the oldest are evicted first. The deduplicator takes `&mut self`, so
+/// wrap it in a `Mutex` to share it between request handlers.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use kick_api::WebhookDeduplicator;
+///
+/// let mut dedup = WebhookDeduplicator::new(10_000, Duration::from_secs(600));
+/// assert!(!dedup.seen("01J5X8Z9K2"));
+/// assert!(dedup.seen("01J5X8Z9K2"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookDeduplicator {
+    capacity: usize,
+    ttl: Duration,
+    seen: HashMap<String, Instant>,
+    order: VecDeque<(String, Instant)>,
+}
+
+impl WebhookDeduplicator {
+    /// Create a deduplicator remembering up to `capacity` IDs for `ttl` each
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            seen: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Check and record a message ID
+    ///
+    /// Returns `true` if the ID was already seen within the TTL (the delivery
+    /// is a duplicate), `false` if it is new.
+    pub fn seen(&mut self, message_id: &str) -> bool {
+        self.seen_at(message_id, Instant::now())
+    }
+
+    /// Check and record a message ID received at a specific time
+    pub fn seen_at(&mut self, message_id: &str, now: Instant) -> bool {
+        self.evict_expired(now);
+
+        if self.seen.contains_key(message_id) {
+            return true;
+        }
+
+        if self.order.len() >= self.capacity
+            && let Some((oldest, _)) = self.order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+
+        self.seen.insert(message_id.to_string(), now);
+        self.order.push_back((message_id.to_string(), now));
+        false
+    }
+
+    /// Number of IDs currently remembered
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Whether no IDs are remembered
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+
+    /// Forget all remembered IDs
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.order.clear();
+    }
+
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((id, at)) = self.order.front() {
+            if now.saturating_duration_since(*at) < self.ttl {
+                break;
+            }
+            self.seen.remove(id);
+            self.order.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_PUBLIC_KEY: &str = "-----BEGIN PUBLIC KEY-----
+MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDvrw8il2eClgkHf1BHAU6XkbGh
+RCY7Ylwm2wwoYI6c+rsli18IV/slSDWTMh8O1kwRw+6vhw5h17tr4IsMiAvpLKpl
+/AIIh2RK4AAvRqk1e+KxLDymYn98MPcc4XXduW4Q/sWqEzp5k6txWVj9SuGgzw3W
+Bq74AepLDlOXK9m33QIDAQAB
+-----END PUBLIC KEY-----";
+
+    #[test]
+    fn test_verify_signature() {
+        let signature = "i+D7sJSkuR5rG/uPDosTOvIsyzoMfoeV7ikENaKs1LYMeHxkoHNmW6/cYCRwOqAVNVnK5TeJYe3DXa3UzLKazRHsabgSBJj5RNk3BAndZczgOYBmzBKULkKy9iEVWdkY7FhqXqTM83W7vKRf4xpUxBdCsjGstsw2ZCxQGA5k25I=";
+        let verify = |body: &[u8]| {
+            verify_signature(
+                TEST_PUBLIC_KEY,
+                "01J5X8Z9K2",
+                "2024-01-01T00:00:00Z",
+                body,
+                signature,
+            )
+        };
+
+        assert!(verify(br#"{"event":"test"}"#).unwrap());
+        assert!(!verify(br#"{"event":"tampered"}"#).unwrap());
+        assert!(verify_signature("not a key", "a", "b", b"", signature).is_err());
+    }
+
+    #[test]
+    fn test_deduplicator_ttl_and_capacity() {
+        let start = Instant::now();
+        let mut dedup = WebhookDeduplicator::new(2, Duration::from_secs(60));
+
+        assert!(!dedup.seen_at("a", start));
+        assert!(dedup.seen_at("a", start + Duration::from_secs(30)));
+
+        // Expired IDs count as new again
+        assert!(!dedup.seen_at("a", start + Duration::from_secs(61)));
+
+        // Capacity evicts the oldest ID
+        let t = start + Duration::from_secs(62);
+        assert!(!dedup.seen_at("b", t));
+        assert!(!dedup.seen_at("c", t));
+        assert_eq!(dedup.len(), 2);
+        assert!(!dedup.seen_at("a", t));
+        assert!(dedup.seen_at("c", t));
+    }
+}